@@ -42,12 +42,16 @@
 pub use core::slice::EscapeAscii;
 #[stable(feature = "slice_get_slice", since = "1.28.0")]
 pub use core::slice::SliceIndex;
+#[unstable(feature = "slice_split_ranges", issue = "none")]
+pub use core::slice::SplitRanges;
 #[stable(feature = "from_ref", since = "1.28.0")]
 pub use core::slice::{from_mut, from_ref};
 #[unstable(feature = "slice_from_ptr_range", issue = "89792")]
 pub use core::slice::{from_mut_ptr_range, from_ptr_range};
 #[stable(feature = "rust1", since = "1.0.0")]
 pub use core::slice::{from_raw_parts, from_raw_parts_mut};
+#[unstable(feature = "slice_chunk_by_key", issue = "none")]
+pub use core::slice::{ChunkByKey, ChunkByKeyMut};
 #[stable(feature = "rust1", since = "1.0.0")]
 pub use core::slice::{Chunks, Windows};
 #[stable(feature = "chunks_exact", since = "1.31.0")]
@@ -66,6 +70,8 @@
 pub use core::slice::{RSplitN, RSplitNMut, SplitN, SplitNMut};
 #[stable(feature = "split_inclusive", since = "1.51.0")]
 pub use core::slice::{SplitInclusive, SplitInclusiveMut};
+#[unstable(feature = "slice_split_inclusive_before", issue = "none")]
+pub use core::slice::{SplitInclusiveBefore, SplitInclusiveBeforeMut};
 
 ////////////////////////////////////////////////////////////////////////////////
 // Basic slice extension methods
@@ -22,14 +22,48 @@
 
 #[stable(feature = "rust1", since = "1.0.0")]
 pub use core::str::pattern;
+#[unstable(feature = "str_valid_prefix_len", issue = "none")]
+pub use core::str::valid_prefix_len;
+#[unstable(feature = "str_char_boundary_indices", issue = "none")]
+pub use core::str::CharBoundaryIndices;
 #[stable(feature = "encode_utf16", since = "1.8.0")]
 pub use core::str::EncodeUtf16;
+#[unstable(feature = "str_escape_ascii", issue = "none")]
+pub use core::str::EscapeAscii;
+#[unstable(feature = "str_escape_control", issue = "none")]
+pub use core::str::EscapeControl;
+#[unstable(feature = "str_lines_lf", issue = "none")]
+pub use core::str::LinesLf;
+#[unstable(feature = "str_char_set", issue = "none")]
+pub use core::str::MatchIndicesCharSet;
+#[unstable(feature = "str_matches_context", issue = "none")]
+pub use core::str::MatchesContext;
+#[unstable(feature = "str_numbered_lines", issue = "none")]
+pub use core::str::NumberedLines;
 #[stable(feature = "split_ascii_whitespace", since = "1.34.0")]
 pub use core::str::SplitAsciiWhitespace;
+#[unstable(feature = "str_split_at_boundaries", issue = "none")]
+pub use core::str::SplitAtBoundaries;
+#[unstable(feature = "str_split_byte_chunks", issue = "none")]
+pub use core::str::SplitByteChunks;
 #[stable(feature = "split_inclusive", since = "1.51.0")]
 pub use core::str::SplitInclusive;
+#[unstable(feature = "str_split_inclusive_coalesced", issue = "none")]
+pub use core::str::SplitInclusiveCoalesced;
+#[unstable(feature = "str_split_on_any_indexed", issue = "none")]
+pub use core::str::SplitOnAnyIndexed;
+#[unstable(feature = "str_split_pairs", issue = "none")]
+pub use core::str::SplitPairs;
+#[unstable(feature = "str_split_trimmed", issue = "none")]
+pub use core::str::SplitTrimmed;
 #[stable(feature = "rust1", since = "1.0.0")]
 pub use core::str::SplitWhitespace;
+#[unstable(feature = "str_split_whitespace_indices", issue = "none")]
+pub use core::str::SplitWhitespaceIndices;
+#[unstable(feature = "str_split_with_offsets", issue = "none")]
+pub use core::str::SplitWithOffsets;
+#[unstable(feature = "str_split_with_separators", issue = "none")]
+pub use core::str::SplitWithSeparators;
 #[stable(feature = "rust1", since = "1.0.0")]
 pub use core::str::{from_utf8, from_utf8_mut, Bytes, CharIndices, Chars};
 #[stable(feature = "rust1", since = "1.0.0")]
@@ -43,12 +77,20 @@
 pub use core::str::{Lines, LinesAny};
 #[stable(feature = "rust1", since = "1.0.0")]
 pub use core::str::{MatchIndices, RMatchIndices};
+#[unstable(feature = "str_matches_and_gaps", issue = "none")]
+pub use core::str::{MatchOrGap, MatchesAndGaps};
+#[unstable(feature = "str_match_ranges", issue = "none")]
+pub use core::str::{MatchRanges, RMatchRanges};
 #[stable(feature = "rust1", since = "1.0.0")]
 pub use core::str::{Matches, RMatches};
+#[unstable(feature = "str_matches_max_items", issue = "none")]
+pub use core::str::{MatchesN, RMatchesN};
 #[stable(feature = "rust1", since = "1.0.0")]
 pub use core::str::{RSplit, Split};
 #[stable(feature = "rust1", since = "1.0.0")]
 pub use core::str::{RSplitN, SplitN};
+#[unstable(feature = "str_split_ranges", issue = "none")]
+pub use core::str::{RSplitRanges, SplitRanges};
 #[stable(feature = "rust1", since = "1.0.0")]
 pub use core::str::{RSplitTerminator, SplitTerminator};
 #[unstable(feature = "utf8_chunks", issue = "99543")]
@@ -280,6 +322,87 @@ pub fn replace<'a, P: Pattern<'a>>(&'a self, from: P, to: &str) -> String {
         result
     }
 
+    /// Replaces all matches of a pattern with strings computed by a closure.
+    ///
+    /// `replace_with` creates a new [`String`], and copies the data from this string slice
+    /// into it. While doing so, it attempts to find matches of a pattern. If it finds any,
+    /// it calls `f` with the byte index and text of the match, and inserts the string it
+    /// returns in place of the match.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(str_replace_with)]
+    ///
+    /// let s = "a.a.a";
+    /// assert_eq!("a1a3a", s.replace_with('.', |i, _| i.to_string()));
+    ///
+    /// let mut n = 0;
+    /// let numbered = "x, y, z".replace_with(char::is_alphabetic, |_, m| {
+    ///     n += 1;
+    ///     format!("{m}{n}")
+    /// });
+    /// assert_eq!(numbered, "x1, y2, z3");
+    /// ```
+    #[cfg(not(no_global_oom_handling))]
+    #[rustc_allow_incoherent_impl]
+    #[must_use = "this returns the replaced string as a new allocation, \
+                  without modifying the original"]
+    #[unstable(feature = "str_replace_with", issue = "none")]
+    pub fn replace_with<'a, P, F>(&'a self, from: P, mut f: F) -> String
+    where
+        P: Pattern<'a>,
+        F: FnMut(usize, &'a str) -> String,
+    {
+        let mut result = String::new();
+        let mut last_end = 0;
+        for (start, part) in self.match_indices(from) {
+            result.push_str(unsafe { self.get_unchecked(last_end..start) });
+            result.push_str(&f(start, part));
+            last_end = start + part.len();
+        }
+        result.push_str(unsafe { self.get_unchecked(last_end..self.len()) });
+        result
+    }
+
+    /// Replaces all matches of a pattern with another string, and returns the
+    /// number of replacements made.
+    ///
+    /// This is the same as [`replace`], but also counts the matches while
+    /// building the new [`String`], so callers can tell whether anything changed
+    /// without searching the string a second time. If the pattern doesn't
+    /// match, the result is a copy of `self` and a count of `0`.
+    ///
+    /// [`replace`]: str::replace
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(str_replace_counted)]
+    ///
+    /// let s = "this is old";
+    /// assert_eq!(s.replace_counted("is", "an"), ("than an old".to_string(), 2));
+    /// assert_eq!(s.replace_counted("cookie monster", "little lamb"), (s.to_string(), 0));
+    /// ```
+    #[cfg(not(no_global_oom_handling))]
+    #[rustc_allow_incoherent_impl]
+    #[must_use = "this returns the replaced string as a new allocation, \
+                  without modifying the original"]
+    #[unstable(feature = "str_replace_counted", issue = "none")]
+    pub fn replace_counted<'a, P: Pattern<'a>>(&'a self, from: P, to: &str) -> (String, usize) {
+        let mut result = String::new();
+        let mut last_end = 0;
+        let mut count = 0;
+        for (start, part) in self.match_indices(from) {
+            result.push_str(unsafe { self.get_unchecked(last_end..start) });
+            result.push_str(to);
+            last_end = start + part.len();
+            count += 1;
+        }
+        result.push_str(unsafe { self.get_unchecked(last_end..self.len()) });
+        (result, count)
+    }
+
     /// Replaces first N matches of a pattern with another string.
     ///
     /// `replacen` creates a new [`String`], and copies the data from this string slice into it.
@@ -321,6 +444,34 @@ pub fn replacen<'a, P: Pattern<'a>>(&'a self, pat: P, to: &str, count: usize) ->
         result
     }
 
+    /// Collects the substrings of this string slice, separated by characters
+    /// matched by a pattern, into a [`Vec`].
+    ///
+    /// This returns the same pieces as `self.split(pat).collect::<Vec<_>>()`,
+    /// but counts them first so that the `Vec` is allocated once, with a
+    /// capacity of exactly the number of pieces. Splitting on an ASCII [`char`]
+    /// counts the separators with a cheap byte scan.
+    ///
+    /// [`char`]: prim@char
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(str_split_collect)]
+    /// let v = "a,b,,c".split_collect(',');
+    /// assert_eq!(v, ["a", "b", "", "c"]);
+    /// assert_eq!(v.capacity(), 4);
+    /// ```
+    #[cfg(not(no_global_oom_handling))]
+    #[rustc_allow_incoherent_impl]
+    #[must_use]
+    #[unstable(feature = "str_split_collect", issue = "none")]
+    pub fn split_collect<'a, P: Pattern<'a> + Clone>(&'a self, pat: P) -> Vec<&'a str> {
+        let mut pieces = Vec::with_capacity(self.split(pat.clone()).count());
+        pieces.extend(self.split(pat));
+        pieces
+    }
+
     /// Returns the lowercase equivalent of this string slice, as a new [`String`].
     ///
     /// 'Lowercase' is defined according to the terms of the Unicode Derived Core Property
@@ -1691,6 +1691,28 @@ pub fn split_off(&mut self, at: usize) -> String {
         unsafe { String::from_utf8_unchecked(other) }
     }
 
+    /// Consumes this `String`, returning an iterator over owned copies of
+    /// its substrings separated by `pat`.
+    ///
+    /// The pieces are the same as those of [`str::split`], but each one is
+    /// yielded as a newly allocated `String`, so the iterator does not
+    /// borrow from anything.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(string_into_split)]
+    /// let s = String::from("a,b,,c");
+    /// let v: Vec<String> = s.into_split(',').collect();
+    /// assert_eq!(v, ["a", "b", "", "c"]);
+    /// ```
+    #[cfg(not(no_global_oom_handling))]
+    #[unstable(feature = "string_into_split", issue = "none")]
+    #[inline]
+    pub fn into_split(self, pat: char) -> IntoSplit {
+        IntoSplit { string: self, position: 0, pat, finished: false }
+    }
+
     /// Truncates this `String`, removing all contents.
     ///
     /// While this means the `String` will have a length of zero, it does not
@@ -2054,10 +2076,7 @@ fn from_iter<I: IntoIterator<Item = Cow<'a, str>>>(iter: I) -> String {
 #[stable(feature = "rust1", since = "1.0.0")]
 impl Extend<char> for String {
     fn extend<I: IntoIterator<Item = char>>(&mut self, iter: I) {
-        let iterator = iter.into_iter();
-        let (lower_bound, _) = iterator.size_hint();
-        self.reserve(lower_bound);
-        iterator.for_each(move |c| self.push(c));
+        <Self as SpecExtendChars<I::IntoIter>>::spec_extend_chars(self, iter.into_iter())
     }
 
     #[inline]
@@ -2071,6 +2090,33 @@ fn extend_reserve(&mut self, additional: usize) {
     }
 }
 
+/// Specialization trait used for `Extend<char>` (and thereby
+/// `FromIterator<char>`) for `String`.
+#[cfg(not(no_global_oom_handling))]
+trait SpecExtendChars<I> {
+    fn spec_extend_chars(&mut self, iter: I);
+}
+
+#[cfg(not(no_global_oom_handling))]
+impl<I: Iterator<Item = char>> SpecExtendChars<I> for String {
+    default fn spec_extend_chars(&mut self, iter: I) {
+        let (lower_bound, _) = iter.size_hint();
+        self.reserve(lower_bound);
+        iter.for_each(move |c| self.push(c));
+    }
+}
+
+#[cfg(not(no_global_oom_handling))]
+impl<'a> SpecExtendChars<Chars<'a>> for String {
+    #[inline]
+    fn spec_extend_chars(&mut self, iter: Chars<'a>) {
+        // `Chars` always sits on a char boundary, so the chars it has left to
+        // yield are exactly the bytes of `as_str`, and they can be copied
+        // without decoding and re-encoding each one.
+        self.push_str(iter.as_str());
+    }
+}
+
 #[cfg(not(no_global_oom_handling))]
 #[stable(feature = "extend_ref", since = "1.2.0")]
 impl<'a> Extend<&'a char> for String {
@@ -2954,6 +3000,53 @@ fn next_back(&mut self) -> Option<char> {
 #[stable(feature = "fused", since = "1.26.0")]
 impl FusedIterator for Drain<'_> {}
 
+/// An iterator over owned substrings of a `String`, separated by a `char`.
+///
+/// This struct is created by the [`into_split`] method on [`String`]. See its
+/// documentation for more.
+///
+/// [`into_split`]: String::into_split
+#[cfg(not(no_global_oom_handling))]
+#[derive(Clone, Debug)]
+#[unstable(feature = "string_into_split", issue = "none")]
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct IntoSplit {
+    string: String,
+    /// Start of the next piece
+    position: usize,
+    pat: char,
+    finished: bool,
+}
+
+#[cfg(not(no_global_oom_handling))]
+#[unstable(feature = "string_into_split", issue = "none")]
+impl Iterator for IntoSplit {
+    type Item = String;
+
+    #[inline]
+    fn next(&mut self) -> Option<String> {
+        if self.finished {
+            return None;
+        }
+
+        let rest = &self.string[self.position..];
+        match rest.find(self.pat) {
+            Some(idx) => {
+                self.position += idx + self.pat.len_utf8();
+                Some(String::from(&rest[..idx]))
+            }
+            None => {
+                self.finished = true;
+                Some(String::from(rest))
+            }
+        }
+    }
+}
+
+#[cfg(not(no_global_oom_handling))]
+#[unstable(feature = "string_into_split", issue = "none")]
+impl FusedIterator for IntoSplit {}
+
 #[cfg(not(no_global_oom_handling))]
 #[stable(feature = "from_char_for_string", since = "1.46.0")]
 impl From<char> for String {
@@ -30,8 +30,63 @@
 #![feature(iter_next_chunk)]
 #![feature(round_char_boundary)]
 #![feature(slice_group_by)]
+#![feature(slice_chunk_by_key)]
 #![feature(slice_partition_dedup)]
+#![feature(slice_split_inclusive_before)]
+#![feature(slice_splitn_double_ended)]
 #![feature(string_remove_matches)]
+#![feature(string_into_split)]
+#![feature(str_split_trimmed)]
+#![feature(str_matches_max_items)]
+#![feature(str_rsplit_at_whitespace)]
+#![feature(str_find_any)]
+#![feature(str_find_map_byte)]
+#![feature(str_find_char)]
+#![feature(str_byte_position)]
+#![feature(str_escape_ascii)]
+#![feature(str_escape_control)]
+#![feature(str_split_whitespace_indices)]
+#![feature(str_matches_as_str)]
+#![feature(str_split_as_str)]
+#![feature(str_split_whitespace_as_str)]
+#![feature(str_split_inclusive_as_str)]
+#![feature(str_split_inclusive_coalesced)]
+#![feature(str_split_inclusive_retain_empty)]
+#![feature(str_split_on_any_indexed)]
+#![feature(str_split_with_separators)]
+#![feature(str_lines_lf)]
+#![feature(str_numbered_lines)]
+#![feature(str_trim_range)]
+#![feature(str_split_collect)]
+#![feature(str_split_find)]
+#![feature(str_split_extend)]
+#![feature(str_split_byte_chunks)]
+#![feature(str_split_max_seps)]
+#![feature(encode_utf16_write_to)]
+#![feature(str_replace_with)]
+#![feature(str_replace_counted)]
+#![feature(str_split_pairs)]
+#![feature(str_split_at_boundaries)]
+#![feature(str_split_with_offsets)]
+#![feature(str_starts_with_any)]
+#![feature(char_indices_offset)]
+#![feature(char_indices_char_count_upper_bound)]
+#![feature(chars_peek)]
+#![feature(str_char_boundary_indices)]
+#![feature(str_char_set)]
+#![feature(str_char_index_of_byte)]
+#![feature(str_matches_context)]
+#![feature(str_matches_at)]
+#![feature(str_match_piece)]
+#![feature(str_matches_and_gaps)]
+#![feature(str_match_ranges)]
+#![feature(str_repeat_into)]
+#![feature(str_eq_ignore_case)]
+#![feature(str_trim_matches_n)]
+#![feature(str_split_prefix)]
+#![feature(str_strip_affixes)]
+#![feature(str_split_ranges)]
+#![feature(str_valid_prefix_len)]
 #![feature(const_btree_new)]
 #![feature(const_default_impls)]
 #![feature(const_trait_impl)]
@@ -887,6 +887,19 @@ fn test_splitator_inclusive_reverse() {
     assert_eq!(xs.split_inclusive(|x| *x == 5).rev().collect::<Vec<_>>(), splits);
 }
 
+#[test]
+fn test_splitator_inclusive_reverse_trailing_match() {
+    // A match in the last position must not produce a trailing empty slice.
+    let xs = &[1, 0, 2, 0];
+    let splits: &[&[_]] = &[&[2, 0], &[1, 0]];
+    assert_eq!(xs.split_inclusive(|x| *x == 0).rev().collect::<Vec<_>>(), splits);
+    let mut it = xs.split_inclusive(|x| *x == 0);
+    assert_eq!(it.next_back(), Some(&[2, 0][..]));
+    assert_eq!(it.next(), Some(&[1, 0][..]));
+    assert_eq!(it.next_back(), None);
+    assert_eq!(it.next(), None);
+}
+
 #[test]
 fn test_splitator_mut_inclusive() {
     let xs = &mut [1, 2, 3, 4, 5];
@@ -927,6 +940,117 @@ fn test_splitator_mut_inclusive_reverse() {
     assert_eq!(xs.split_inclusive_mut(|x| *x == 5).rev().collect::<Vec<_>>(), splits);
 }
 
+#[test]
+fn test_splitator_mut_inclusive_reverse_trailing_match() {
+    // A match in the last position must not produce a trailing empty slice.
+    let xs = &mut [1, 0, 2, 0];
+    let splits: &[&[_]] = &[&[2, 0], &[1, 0]];
+    assert_eq!(xs.split_inclusive_mut(|x| *x == 0).rev().collect::<Vec<_>>(), splits);
+    let mut it = xs.split_inclusive_mut(|x| *x == 0);
+    assert_eq!(it.next_back(), Some(&mut [2, 0][..]));
+    assert_eq!(it.next(), Some(&mut [1, 0][..]));
+    assert_eq!(it.next_back(), None);
+    assert_eq!(it.next(), None);
+}
+
+#[test]
+fn test_splitator_inclusive_before() {
+    let xs = &[1, 2, 3, 4, 5];
+
+    let splits: &[&[_]] = &[&[1], &[2, 3], &[4, 5]];
+    assert_eq!(xs.split_inclusive_before(|x| *x % 2 == 0).collect::<Vec<_>>(), splits);
+    let splits: &[&[_]] = &[&[1, 2, 3, 4, 5]];
+    assert_eq!(xs.split_inclusive_before(|x| *x == 1).collect::<Vec<_>>(), splits);
+    let splits: &[&[_]] = &[&[1, 2, 3, 4], &[5]];
+    assert_eq!(xs.split_inclusive_before(|x| *x == 5).collect::<Vec<_>>(), splits);
+    let splits: &[&[_]] = &[&[1, 2, 3, 4, 5]];
+    assert_eq!(xs.split_inclusive_before(|x| *x == 10).collect::<Vec<_>>(), splits);
+    let splits: &[&[_]] = &[&[1], &[2], &[3], &[4], &[5]];
+    assert_eq!(xs.split_inclusive_before(|_| true).collect::<Vec<_>>(), splits);
+
+    let xs: &[i32] = &[];
+    let splits: &[&[i32]] = &[];
+    assert_eq!(xs.split_inclusive_before(|x| *x == 5).collect::<Vec<_>>(), splits);
+}
+
+#[test]
+fn test_splitator_inclusive_before_reverse() {
+    let xs = &[1, 2, 3, 4, 5];
+
+    let splits: &[&[_]] = &[&[4, 5], &[2, 3], &[1]];
+    assert_eq!(xs.split_inclusive_before(|x| *x % 2 == 0).rev().collect::<Vec<_>>(), splits);
+    let splits: &[&[_]] = &[&[1, 2, 3, 4, 5]];
+    assert_eq!(xs.split_inclusive_before(|x| *x == 1).rev().collect::<Vec<_>>(), splits);
+    let splits: &[&[_]] = &[&[5], &[1, 2, 3, 4]];
+    assert_eq!(xs.split_inclusive_before(|x| *x == 5).rev().collect::<Vec<_>>(), splits);
+    let splits: &[&[_]] = &[&[1, 2, 3, 4, 5]];
+    assert_eq!(xs.split_inclusive_before(|x| *x == 10).rev().collect::<Vec<_>>(), splits);
+    let splits: &[&[_]] = &[&[5], &[4], &[3], &[2], &[1]];
+    assert_eq!(xs.split_inclusive_before(|_| true).rev().collect::<Vec<_>>(), splits);
+
+    let xs: &[i32] = &[];
+    let splits: &[&[i32]] = &[];
+    assert_eq!(xs.split_inclusive_before(|x| *x == 5).rev().collect::<Vec<_>>(), splits);
+}
+
+#[test]
+fn test_splitator_inclusive_before_double_ended() {
+    let xs = &[1, 0, 2, 0, 3];
+
+    let mut iter = xs.split_inclusive_before(|x| *x == 0);
+    assert_eq!(iter.next_back(), Some(&[0, 3][..]));
+    assert_eq!(iter.next(), Some(&[1][..]));
+    assert_eq!(iter.next_back(), Some(&[0, 2][..]));
+    assert_eq!(iter.next(), None);
+    assert_eq!(iter.next_back(), None);
+
+    let xs = &[0, 0, 1];
+    let splits: &[&[_]] = &[&[0], &[0, 1]];
+    assert_eq!(xs.split_inclusive_before(|x| *x == 0).collect::<Vec<_>>(), splits);
+    let splits: &[&[_]] = &[&[0, 1], &[0]];
+    assert_eq!(xs.split_inclusive_before(|x| *x == 0).rev().collect::<Vec<_>>(), splits);
+}
+
+#[test]
+fn test_splitator_mut_inclusive_before() {
+    let xs = &mut [1, 2, 3, 4, 5];
+
+    let splits: &[&[_]] = &[&[1], &[2, 3], &[4, 5]];
+    assert_eq!(xs.split_inclusive_before_mut(|x| *x % 2 == 0).collect::<Vec<_>>(), splits);
+    let splits: &[&[_]] = &[&[1, 2, 3, 4, 5]];
+    assert_eq!(xs.split_inclusive_before_mut(|x| *x == 1).collect::<Vec<_>>(), splits);
+    let splits: &[&[_]] = &[&[1, 2, 3, 4], &[5]];
+    assert_eq!(xs.split_inclusive_before_mut(|x| *x == 5).collect::<Vec<_>>(), splits);
+    let splits: &[&[_]] = &[&[1, 2, 3, 4, 5]];
+    assert_eq!(xs.split_inclusive_before_mut(|x| *x == 10).collect::<Vec<_>>(), splits);
+    let splits: &[&[_]] = &[&[1], &[2], &[3], &[4], &[5]];
+    assert_eq!(xs.split_inclusive_before_mut(|_| true).collect::<Vec<_>>(), splits);
+
+    let xs: &mut [i32] = &mut [];
+    let splits: &[&[i32]] = &[];
+    assert_eq!(xs.split_inclusive_before_mut(|x| *x == 5).collect::<Vec<_>>(), splits);
+}
+
+#[test]
+fn test_splitator_mut_inclusive_before_reverse() {
+    let xs = &mut [1, 2, 3, 4, 5];
+
+    let splits: &[&[_]] = &[&[4, 5], &[2, 3], &[1]];
+    assert_eq!(xs.split_inclusive_before_mut(|x| *x % 2 == 0).rev().collect::<Vec<_>>(), splits);
+    let splits: &[&[_]] = &[&[1, 2, 3, 4, 5]];
+    assert_eq!(xs.split_inclusive_before_mut(|x| *x == 1).rev().collect::<Vec<_>>(), splits);
+    let splits: &[&[_]] = &[&[5], &[1, 2, 3, 4]];
+    assert_eq!(xs.split_inclusive_before_mut(|x| *x == 5).rev().collect::<Vec<_>>(), splits);
+    let splits: &[&[_]] = &[&[1, 2, 3, 4, 5]];
+    assert_eq!(xs.split_inclusive_before_mut(|x| *x == 10).rev().collect::<Vec<_>>(), splits);
+    let splits: &[&[_]] = &[&[5], &[4], &[3], &[2], &[1]];
+    assert_eq!(xs.split_inclusive_before_mut(|_| true).rev().collect::<Vec<_>>(), splits);
+
+    let xs: &mut [i32] = &mut [];
+    let splits: &[&[i32]] = &[];
+    assert_eq!(xs.split_inclusive_before_mut(|x| *x == 5).rev().collect::<Vec<_>>(), splits);
+}
+
 #[test]
 fn test_splitnator() {
     let xs = &[1, 2, 3, 4, 5];
@@ -994,6 +1118,83 @@ fn test_rsplitnator() {
     assert!(xs.rsplitn(0, |x| *x % 2 == 0).next().is_none());
 }
 
+#[test]
+fn test_splitnator_double_ended() {
+    let xs = &[1, 0, 2, 0, 3];
+    let mut iter = xs.splitn(2, |x| *x == 0);
+    assert_eq!(iter.next_back(), Some(&[2, 0, 3][..]));
+    assert_eq!(iter.next(), Some(&[1][..]));
+    assert_eq!(iter.next_back(), None);
+    assert_eq!(iter.next(), None);
+
+    let mut iter = xs.rsplitn(2, |x| *x == 0);
+    assert_eq!(iter.next_back(), Some(&[1, 0, 2][..]));
+    assert_eq!(iter.next(), Some(&[3][..]));
+    assert_eq!(iter.next(), None);
+
+    // Drives `iter` with `next` and `next_back` as picked by the bits of
+    // `mask`, checking that it yields the pieces of `forward` from either end.
+    fn check(mut iter: impl DoubleEndedIterator<Item = Vec<i32>>, forward: &[Vec<i32>], mask: u32) {
+        let (mut front, mut back) = (0, forward.len());
+        for step in 0..=forward.len() {
+            if mask & (1 << step) == 0 {
+                let expected = (front < back).then(|| forward[front].clone());
+                assert_eq!(iter.next(), expected);
+                front += (front < back) as usize;
+            } else {
+                let expected = (front < back).then(|| forward[back - 1].clone());
+                assert_eq!(iter.next_back(), expected);
+                back -= (front < back) as usize;
+            }
+        }
+    }
+
+    for xs in [&[1, 0, 2, 0, 3][..], &[0, 0, 1, 0], &[1], &[]] {
+        for n in 0..6 {
+            let forward: Vec<Vec<i32>> = xs.splitn(n, |x| *x == 0).map(|s| s.to_vec()).collect();
+            let rforward: Vec<Vec<i32>> = xs.rsplitn(n, |x| *x == 0).map(|s| s.to_vec()).collect();
+            assert!(forward.len() <= n && rforward.len() <= n);
+
+            for mask in 0u32..1 << 6 {
+                check(xs.splitn(n, |x| *x == 0).map(|s| s.to_vec()), &forward, mask);
+                check(xs.rsplitn(n, |x| *x == 0).map(|s| s.to_vec()), &rforward, mask);
+
+                let mut ys = xs.to_vec();
+                check(ys.splitn_mut(n, |x| *x == 0).map(|s| s.to_vec()), &forward, mask);
+                check(ys.rsplitn_mut(n, |x| *x == 0).map(|s| s.to_vec()), &rforward, mask);
+            }
+        }
+    }
+}
+
+#[test]
+fn test_splitnator_rev_linear() {
+    // Iterating from the back only scans forward for the budget once, so the
+    // predicate is called a bounded number of times per element.
+    let xs = [0; 100];
+    let mut calls = 0;
+    let pieces = xs
+        .splitn(xs.len(), |x| {
+            calls += 1;
+            *x == 0
+        })
+        .rev()
+        .count();
+    assert_eq!(pieces, xs.len());
+    assert!(calls <= 2 * xs.len());
+
+    let mut calls = 0;
+    let pieces = xs
+        .rsplitn(xs.len(), |x| {
+            calls += 1;
+            *x == 0
+        })
+        .rev()
+        .count();
+    assert_eq!(pieces, xs.len());
+    assert!(calls <= 2 * xs.len());
+}
+
 #[test]
 fn test_split_iterators_size_hint() {
     #[derive(Copy, Clone)]
@@ -2016,3 +2217,99 @@ fn test_group_by_mut() {
     assert_eq!(iter.next(), Some(&mut [2, 2, 2][..]));
     assert_eq!(iter.next_back(), None);
 }
+
+#[test]
+fn test_group_by_short() {
+    let runs: Vec<_> = [1, 1, 2, 3, 3].group_by(|a, b| a == b).collect();
+    assert_eq!(runs, [&[1, 1][..], &[2], &[3, 3]]);
+
+    let mut iter = [5].group_by(|a, b| a == b);
+    assert_eq!(iter.next_back(), Some(&[5][..]));
+    assert_eq!(iter.next(), None);
+
+    let empty: &[i32] = &[];
+    assert_eq!(empty.group_by(|a, b| a == b).next(), None);
+    assert_eq!(empty.group_by(|a, b| a == b).next_back(), None);
+}
+
+#[test]
+fn test_group_by_mut_short() {
+    let mut single = [5];
+    let mut iter = single.group_by_mut(|a, b| a == b);
+    assert_eq!(iter.next(), Some(&mut [5][..]));
+    assert_eq!(iter.next_back(), None);
+
+    let empty: &mut [i32] = &mut [];
+    assert_eq!(empty.group_by_mut(|a, b| a == b).next(), None);
+}
+
+#[test]
+fn test_chunk_by_key() {
+    let slice = &[("a", 1), ("a", 2), ("b", 3), ("a", 4)];
+
+    let mut iter = slice.chunk_by_key(|&(name, _)| name);
+    assert_eq!(iter.next(), Some(&[("a", 1), ("a", 2)][..]));
+    assert_eq!(iter.next(), Some(&[("b", 3)][..]));
+    assert_eq!(iter.next(), Some(&[("a", 4)][..]));
+    assert_eq!(iter.next(), None);
+
+    let mut iter = slice.chunk_by_key(|&(name, _)| name);
+    assert_eq!(iter.next_back(), Some(&[("a", 4)][..]));
+    assert_eq!(iter.next_back(), Some(&[("b", 3)][..]));
+    assert_eq!(iter.next_back(), Some(&[("a", 1), ("a", 2)][..]));
+    assert_eq!(iter.next_back(), None);
+
+    // A key that changes on every element yields all singletons.
+    let slice = &[1, 1, 2, 2, 3];
+    let mut i = 0;
+    let runs: Vec<_> = slice
+        .chunk_by_key(|_| {
+            i += 1;
+            i
+        })
+        .collect();
+    assert_eq!(runs, [&[1][..], &[1], &[2], &[2], &[3]]);
+
+    // Only adjacent keys are compared.
+    let slice = &[1, 3, 2, 4, 4, 5];
+    let runs: Vec<_> = slice.chunk_by_key(|n| n % 2).collect();
+    assert_eq!(runs, [&[1, 3][..], &[2, 4, 4], &[5]]);
+    let runs: Vec<_> = slice.chunk_by_key(|n| n % 2).rev().collect();
+    assert_eq!(runs, [&[5][..], &[2, 4, 4], &[1, 3]]);
+
+    let empty: &[i32] = &[];
+    assert_eq!(empty.chunk_by_key(|x| *x).next(), None);
+    assert_eq!(empty.chunk_by_key(|x| *x).next_back(), None);
+}
+
+#[test]
+fn test_chunk_by_key_mut() {
+    let slice = &mut [("a", 1), ("a", 2), ("b", 3), ("a", 4)];
+
+    let mut iter = slice.chunk_by_key_mut(|&(name, _)| name);
+    assert_eq!(iter.next(), Some(&mut [("a", 1), ("a", 2)][..]));
+    assert_eq!(iter.next_back(), Some(&mut [("a", 4)][..]));
+    assert_eq!(iter.next(), Some(&mut [("b", 3)][..]));
+    assert_eq!(iter.next_back(), None);
+
+    // A key that changes on every element yields all singletons.
+    let slice = &mut [1, 1, 2, 2, 3];
+    let mut i = 0;
+    for run in slice.chunk_by_key_mut(|_| {
+        i += 1;
+        i
+    }) {
+        assert_eq!(run.len(), 1);
+        run[0] *= 10;
+    }
+    assert_eq!(slice, &[10, 10, 20, 20, 30]);
+
+    let slice = &mut [1, 3, 2, 4, 4, 5];
+    for run in slice.chunk_by_key_mut(|n| n % 2) {
+        run.reverse();
+    }
+    assert_eq!(slice, &[3, 1, 4, 4, 2, 5]);
+
+    let empty: &mut [i32] = &mut [];
+    assert_eq!(empty.chunk_by_key_mut(|x| *x).next(), None);
+}
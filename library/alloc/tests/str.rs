@@ -241,6 +241,61 @@ fn test_ends_with() {
     assert!("ddö".ends_with("dö"));
 }
 
+#[test]
+fn test_starts_with_any() {
+    assert_eq!("foobar".starts_with_any(&["foo", "foobar"]), Some(0));
+    assert_eq!("foobar".starts_with_any(&["foobar", "foo"]), Some(0));
+    assert_eq!("foobar".starts_with_any(&["bar", "fo"]), Some(1));
+    assert_eq!("foobar".starts_with_any(&["bar", "foobarbaz"]), None);
+    assert_eq!("foobar".starts_with_any(&[]), None);
+    assert_eq!("".starts_with_any(&["a", ""]), Some(1));
+    assert_eq!("ödd".starts_with_any(&["o", "ö"]), Some(1));
+}
+
+#[test]
+fn test_ends_with_any() {
+    assert_eq!("foobar".ends_with_any(&["bar", "foobar"]), Some(0));
+    assert_eq!("foobar".ends_with_any(&["foobar", "bar"]), Some(0));
+    assert_eq!("foobar".ends_with_any(&["foo", "ar"]), Some(1));
+    assert_eq!("foobar".ends_with_any(&["foo", "bazfoobar"]), None);
+    assert_eq!("foobar".ends_with_any(&[]), None);
+    assert_eq!("".ends_with_any(&["a", ""]), Some(1));
+    assert_eq!("ddö".ends_with_any(&["o", "ö"]), Some(1));
+}
+
+#[test]
+fn test_matches_at() {
+    let s = "foobar";
+    assert_eq!(s.matches_at(3, "bar"), Some(6));
+    assert_eq!(s.matches_at(0, "foo"), Some(3));
+    assert_eq!(s.matches_at(2, "bar"), None);
+    assert_eq!(s.matches_at(4, "bar"), None);
+    assert_eq!(s.matches_at(0, "bar"), None);
+    assert_eq!(s.matches_at(3, "barbaz"), None);
+    assert_eq!(s.matches_at(2, ""), Some(2));
+    assert_eq!(s.matches_at(6, ""), Some(6));
+    assert_eq!(s.matches_at(6, 'r'), None);
+
+    let s = "ödd中华";
+    assert_eq!(s.matches_at(0, 'ö'), Some(2));
+    assert_eq!(s.matches_at(2, ['c', 'd']), Some(3));
+    assert_eq!(s.matches_at(4, |c: char| !c.is_ascii()), Some(7));
+    assert_eq!(s.matches_at(7, "华"), Some(10));
+    assert_eq!(s.matches_at(4, "华"), None);
+}
+
+#[test]
+#[should_panic(expected = "byte index 1 is not a char boundary")]
+fn test_matches_at_not_char_boundary() {
+    let _ = "ödd".matches_at(1, 'd');
+}
+
+#[test]
+#[should_panic(expected = "byte index 5 is out of bounds")]
+fn test_matches_at_out_of_bounds() {
+    let _ = "ödd".matches_at(5, "");
+}
+
 #[test]
 fn test_is_empty() {
     assert!("".is_empty());
@@ -272,6 +327,39 @@ fn test_replace() {
     assert_eq!(" test test ".replace(test, ""), "   ");
 }
 
+#[test]
+fn test_replace_with() {
+    assert_eq!("a.a.a".replace_with('.', |i, _| i.to_string()), "a1a3a");
+    assert_eq!("".replace_with('.', |_, _| unreachable!()), "");
+    assert_eq!("abc".replace_with('.', |_, _| unreachable!()), "abc");
+
+    let mut seen = Vec::new();
+    let s = "ä->b->->c".replace_with("->", |i, m| {
+        seen.push((i, m));
+        format!("<{}>", seen.len())
+    });
+    assert_eq!(s, "ä<1>b<2><3>c");
+    assert_eq!(seen, [(2, "->"), (5, "->"), (7, "->")]);
+
+    assert_eq!("Märy häd".replace_with(char::is_whitespace, |_, _| String::new()), "Märyhäd");
+    assert_eq!("ab".replace_with("", |i, _| i.to_string()), "0a1b2");
+}
+
+#[test]
+fn test_replace_counted() {
+    assert_eq!("".replace_counted('.', "x"), (String::new(), 0));
+    assert_eq!("abc".replace_counted('.', "x"), ("abc".to_string(), 0));
+    assert_eq!("abc".replace_counted("cookie monster", "x"), ("abc".to_string(), 0));
+
+    assert_eq!("a.a.a".replace_counted('.', "-"), ("a-a-a".to_string(), 2));
+    assert_eq!("ä->b->->c".replace_counted("->", "→"), ("ä→b→→c".to_string(), 3));
+    assert_eq!("Märy häd".replace_counted(char::is_alphabetic, ""), (" ".to_string(), 7));
+    assert_eq!("ab".replace_counted("", "-"), ("-a-b-".to_string(), 3));
+
+    let data = "ประเทศไทย中华Việt Nam; Mary had a little lamb, Little lamb";
+    assert_eq!(data.replace_counted("lamb", "calf").0, data.replace("lamb", "calf"));
+}
+
 #[test]
 fn test_replace_2a() {
     let data = "ประเทศไทย中华";
@@ -820,6 +908,89 @@ fn test_trim_matches() {
     assert_eq!("123foo1bar123".trim_matches(|c: char| c.is_numeric()), "foo1bar");
 }
 
+#[test]
+fn test_trim_start_matches_n() {
+    assert_eq!("...abc".trim_start_matches_n('.', 2), ".abc");
+    assert_eq!("...abc".trim_start_matches_n('.', 5), "abc");
+    assert_eq!("...abc".trim_start_matches_n('.', 0), "...abc");
+    assert_eq!("...".trim_start_matches_n('.', usize::MAX), "");
+    assert_eq!("".trim_start_matches_n('.', 2), "");
+
+    assert_eq!("foofoobar".trim_start_matches_n("foo", 1), "foobar");
+    assert_eq!("foofoobar".trim_start_matches_n("foo", 9), "bar");
+    assert_eq!("xfoo".trim_start_matches_n("foo", 3), "xfoo");
+    assert_eq!("ab".trim_start_matches_n("", 3), "ab");
+    assert_eq!("ééa".trim_start_matches_n('é', 1), "éa");
+    assert_eq!("12ab".trim_start_matches_n(char::is_numeric, 1), "2ab");
+}
+
+#[test]
+fn test_trim_end_matches_n() {
+    assert_eq!("abc...".trim_end_matches_n('.', 2), "abc.");
+    assert_eq!("abc...".trim_end_matches_n('.', 5), "abc");
+    assert_eq!("abc...".trim_end_matches_n('.', 0), "abc...");
+    assert_eq!("...".trim_end_matches_n('.', usize::MAX), "");
+    assert_eq!("".trim_end_matches_n('.', 2), "");
+
+    assert_eq!("barfoofoo".trim_end_matches_n("foo", 1), "barfoo");
+    assert_eq!("foofoo".trim_end_matches_n("foo", 9), "");
+    assert_eq!("foox".trim_end_matches_n("foo", 3), "foox");
+    assert_eq!("aéé".trim_end_matches_n('é', 1), "aé");
+    assert_eq!("ab12".trim_end_matches_n(char::is_numeric, 1), "ab1");
+}
+
+#[test]
+fn test_strip_affixes() {
+    assert_eq!("<p>text</p>".strip_affixes("<p>", "</p>"), Some("text"));
+    assert_eq!("<p></p>".strip_affixes("<p>", "</p>"), Some(""));
+    assert_eq!("<p>text".strip_affixes("<p>", "</p>"), None);
+    assert_eq!("text</p>".strip_affixes("<p>", "</p>"), None);
+    assert_eq!("".strip_affixes("", ""), Some(""));
+
+    // The affixes may not overlap, even where both match on their own.
+    assert_eq!("aba".strip_affixes("ab", "ba"), None);
+    assert_eq!("abba".strip_affixes("ab", "ba"), Some(""));
+    assert_eq!("a".strip_affixes('a', 'a'), None);
+    assert_eq!("aa".strip_affixes('a', 'a'), Some(""));
+    assert_eq!("<p>".strip_affixes("<p>", ">"), None);
+
+    // Partial matches of either affix are not stripped.
+    assert_eq!("<ptext</p>".strip_affixes("<p>", "</p>"), None);
+    assert_eq!("<p>text</".strip_affixes("<p>", "</p>"), None);
+
+    assert_eq!("«é»".strip_affixes('«', '»'), Some("é"));
+    assert_eq!("(1]".strip_affixes(['(', '['], [')', ']']), Some("1"));
+    assert_eq!("1ab2".strip_affixes(char::is_numeric, |c: char| c.is_numeric()), Some("ab"));
+}
+
+#[test]
+fn test_split_prefix() {
+    assert_eq!("Hello, world".split_prefix("Hello"), Some(("Hello", ", world")));
+    assert_eq!("Hello, world".split_prefix("world"), None);
+    assert_eq!("Hello".split_prefix("Hello"), Some(("Hello", "")));
+    assert_eq!("Hello".split_prefix(""), Some(("", "Hello")));
+    assert_eq!("".split_prefix('a'), None);
+
+    assert_eq!("ébc".split_prefix('é'), Some(("é", "bc")));
+    assert_eq!("-1".split_prefix(['+', '-']), Some(("-", "1")));
+    assert_eq!("12ab".split_prefix(char::is_numeric), Some(("1", "2ab")));
+    assert_eq!("ab12".split_prefix(|c: char| c.is_numeric()), None);
+}
+
+#[test]
+fn test_split_suffix() {
+    assert_eq!("Hello, world".split_suffix("world"), Some(("Hello, ", "world")));
+    assert_eq!("Hello, world".split_suffix("Hello"), None);
+    assert_eq!("world".split_suffix("world"), Some(("", "world")));
+    assert_eq!("world".split_suffix(""), Some(("world", "")));
+    assert_eq!("".split_suffix('a'), None);
+
+    assert_eq!("abé".split_suffix('é'), Some(("ab", "é")));
+    assert_eq!("1‰".split_suffix(['%', '‰']), Some(("1", "‰")));
+    assert_eq!("ab12".split_suffix(char::is_numeric), Some(("ab1", "2")));
+    assert_eq!("12ab".split_suffix(|c: char| c.is_numeric()), None);
+}
+
 #[test]
 fn test_trim_start() {
     assert_eq!("".trim_start(), "");
@@ -850,6 +1021,31 @@ fn test_trim() {
     assert_eq!(" hey dude ".trim(), "hey dude");
 }
 
+#[test]
+fn test_trim_range() {
+    assert_eq!("".trim_range(), 0..0);
+    assert_eq!("".trim_start_range(), 0..0);
+    assert_eq!("".trim_end_range(), 0..0);
+
+    // all whitespace
+    assert_eq!(" \t\u{3000} ".trim_range(), 0..0);
+    assert_eq!(" \t\u{3000} ".trim_start_range(), 6..6);
+    assert_eq!(" \t\u{3000} ".trim_end_range(), 0..0);
+
+    // no whitespace
+    assert_eq!("hey".trim_range(), 0..3);
+    assert_eq!("hey".trim_start_range(), 0..3);
+    assert_eq!("hey".trim_end_range(), 0..3);
+
+    let s = "\u{3000} hey dude\n";
+    assert_eq!(s.trim_range(), 4..12);
+    assert_eq!(s.trim_start_range(), 4..13);
+    assert_eq!(s.trim_end_range(), 0..12);
+    assert_eq!(&s[s.trim_range()], s.trim());
+    assert_eq!(&s[s.trim_start_range()], s.trim_start());
+    assert_eq!(&s[s.trim_end_range()], s.trim_end());
+}
+
 #[test]
 fn test_is_whitespace() {
     assert!("".chars().all(|c| c.is_whitespace()));
@@ -983,6 +1179,31 @@ macro_rules! test {
     test!(b"A\xC3\xA9 \xF1\x80\x80 ", 4, Some(3));
 }
 
+#[test]
+fn test_valid_prefix_len() {
+    use std::str::valid_prefix_len;
+
+    assert_eq!(valid_prefix_len(b""), 0);
+    assert_eq!(valid_prefix_len("ab\u{e9}\u{4e2d}\u{1f496}".as_bytes()), 11);
+
+    // Truncated two-, three- and four-byte sequences at the end.
+    assert_eq!(valid_prefix_len(b"ab\xC3"), 2);
+    assert_eq!(valid_prefix_len(b"ab\xE4\xB8"), 2);
+    assert_eq!(valid_prefix_len(b"ab\xF0\x9F\x92"), 2);
+
+    // Invalid bytes in the middle.
+    assert_eq!(valid_prefix_len(b"A\xC3\xA9 \xFF \xC3\xA9"), 4);
+    assert_eq!(valid_prefix_len(b"\x80abc"), 0);
+
+    for input in [&b"A\xC3\xA9 \xE0\xA0 "[..], b"\xED\xA0\x80", b"abc\xF1\x80\x80"] {
+        let expected = from_utf8(input).map_or_else(|e| e.valid_up_to(), |s| s.len());
+        assert_eq!(valid_prefix_len(input), expected);
+    }
+
+    const LEN: usize = valid_prefix_len(b"ab\xF0\x9F");
+    assert_eq!(LEN, 2);
+}
+
 #[test]
 fn test_as_bytes() {
     // no null
@@ -1053,6 +1274,84 @@ fn test_contains() {
     assert!(!data.contains("ไท华"));
 }
 
+#[test]
+fn test_contains_any() {
+    assert!("abcde".contains_any(&["xyz", "bcd"]));
+    assert!("abcde".contains_any(&["", "xyz"]));
+    assert!(!"abcde".contains_any(&["xyz", "edc"]));
+    assert!(!"abcde".contains_any(&[]));
+    assert!(!"".contains_any(&["a"]));
+}
+
+#[test]
+fn test_find_any() {
+    let data = "ประเทศไทย中华Việt Nam";
+    assert_eq!(data.find_any(&["Nam", "中华", "ไท"]), Some((18, 2)));
+    assert_eq!(data.find_any(&["Nam", "han"]), Some((40, 0)));
+
+    // overlapping patterns: the earliest start wins, ties go to the first listed
+    assert_eq!("abcabc".find_any(&["bca", "abc"]), Some((0, 1)));
+    assert_eq!("abcabc".find_any(&["ab", "abc"]), Some((0, 0)));
+    assert_eq!("abcabc".find_any(&["abc", "ab"]), Some((0, 0)));
+    assert_eq!("abcabc".find_any(&["cab", "ca"]), Some((2, 0)));
+    assert_eq!("abcabc".find_any(&["x", ""]), Some((0, 1)));
+
+    assert_eq!("abcabc".find_any(&["x", "y"]), None);
+    assert_eq!("abcabc".find_any(&[]), None);
+}
+
+#[test]
+fn test_find_char() {
+    let data = "Märy häd ä little lämb 老虎";
+    for c in ['M', 'ä', 'l', 'b', ' ', '老', '虎', 'x', '\u{1F4A9}'] {
+        assert_eq!(data.find_char(c), data.find(c));
+        assert_eq!(data.rfind_char(c), data.rfind(c));
+    }
+
+    // offsets are byte-based
+    assert_eq!(data.find_char('ä'), Some(1));
+    assert_eq!(data.rfind_char('ä'), Some(22));
+    assert_eq!(data.find_char('r'), Some(3));
+    assert_eq!(data.rfind_char('虎'), Some(30));
+
+    assert_eq!("".find_char('a'), None);
+    assert_eq!("".rfind_char('a'), None);
+}
+
+#[test]
+fn test_byte_position() {
+    let data = "Märy häd ä little lämb 老虎";
+    for b in [b'M', b'r', b' ', b'b', b'x', 0xC3, 0xA4, 0xE8, 0x99, 0xFF] {
+        assert_eq!(data.byte_position(b), data.bytes().position(|x| x == b));
+    }
+
+    // offsets are byte-based, even inside multi-byte characters
+    assert_eq!(data.byte_position(b'r'), Some(3));
+    assert_eq!(data.byte_position(0xC3), Some(1));
+    assert_eq!(data.byte_position(0xA4), Some(2));
+
+    assert_eq!("".byte_position(b'a'), None);
+    assert_eq!("\0".byte_position(0), Some(0));
+}
+
+#[test]
+fn test_find_map_byte() {
+    assert_eq!("a1b".find_map_byte(|c| c.to_digit(10)), Some((1, 1)));
+    assert_eq!("a1b2".find_map_byte(|c| c.to_digit(10)), Some((1, 1)));
+    assert_eq!("äö9".find_map_byte(|c| c.to_digit(10)), Some((4, 9)));
+    assert_eq!("abc".find_map_byte(|c| c.to_digit(10)), None);
+    assert_eq!("".find_map_byte(|c| Some(c)), None);
+
+    // `f` is not called again once it returns `Some`
+    let mut calls = 0;
+    let r = "xyz".find_map_byte(|c| {
+        calls += 1;
+        (c == 'y').then_some(c)
+    });
+    assert_eq!(r, Some((1, 'y')));
+    assert_eq!(calls, 2);
+}
+
 #[test]
 fn test_contains_char() {
     assert!("abc".contains('b'));
@@ -1143,6 +1442,63 @@ fn test_escape_default() {
     assert_eq!("\u{10d4ea}\r".escape_default().to_string(), "\\u{10d4ea}\\r");
 }
 
+#[test]
+fn test_escape_default_last() {
+    assert_eq!("a\n".escape_default().last(), Some('n'));
+    assert_eq!("é".escape_default().last(), Some('}'));
+    assert_eq!("ab".escape_default().last(), Some('b'));
+    assert_eq!("".escape_default().last(), None);
+
+    // Partially consumed escapes of the final char are accounted for.
+    for s in ["a\n", "\t", "x\u{e9}", "\u{10ffff}"] {
+        let mut iter = s.escape_default();
+        loop {
+            let expected = iter.clone().fold(None, |_, c| Some(c));
+            assert_eq!(iter.clone().last(), expected);
+            if iter.next().is_none() {
+                break;
+            }
+        }
+    }
+}
+
+#[test]
+fn test_escape_ascii() {
+    assert_eq!("abc".escape_ascii().to_string(), "abc");
+    assert_eq!("a\t€".escape_ascii().to_string(), "a\\t\\xe2\\x82\\xac");
+    assert_eq!("'\"\\".escape_ascii().to_string(), "\\'\\\"\\\\");
+    assert_eq!("\u{7f}\r\n".escape_ascii().to_string(), "\\x7f\\r\\n");
+
+    let fwd: String = "é!".escape_ascii().collect();
+    assert_eq!(fwd, "\\xc3\\xa9!");
+    let rev: String = "é!".escape_ascii().rev().collect();
+    assert_eq!(rev, "!9ax\\3cx\\");
+}
+
+#[test]
+fn test_escape_ascii_count_last() {
+    assert_eq!("a\t€".escape_ascii().count(), 15);
+    assert_eq!("a\t€".escape_ascii().last(), Some('c'));
+    assert_eq!("é!".escape_ascii().last(), Some('!'));
+    assert_eq!("".escape_ascii().count(), 0);
+    assert_eq!("".escape_ascii().last(), None);
+}
+
+#[test]
+fn test_escape_control() {
+    assert_eq!("a\u{7}b\u{1b}c".escape_control().to_string(), "a\\u{7}b\\u{1b}c");
+    assert_eq!("\0\t\r\n".escape_control().to_string(), "\\0\\t\\r\\n");
+    assert_eq!("\u{7f}\u{80}\u{9f}".escape_control().to_string(), "\\u{7f}\\u{80}\\u{9f}");
+
+    // Everything else is left verbatim, unlike `escape_debug`.
+    assert_eq!("'\"\\".escape_control().to_string(), "'\"\\");
+    assert_eq!("\u{301}é🦀\u{200b}".escape_control().to_string(), "\u{301}é🦀\u{200b}");
+    assert_eq!("".escape_control().to_string(), "");
+
+    let v: Vec<char> = "\u{7}x".escape_control().collect();
+    assert_eq!(v, ['\\', 'u', '{', '7', '}', 'x']);
+}
+
 #[test]
 fn test_total_ord() {
     assert_eq!("1234".cmp("123"), Greater);
@@ -1183,6 +1539,68 @@ fn test_rev_iterator() {
     assert_eq!(pos, v.len());
 }
 
+#[test]
+fn test_chars_peek() {
+    let s = "ศไทย中华Việt Nam🦀";
+    let mut it = s.chars();
+    while let Some(c) = it.peek() {
+        assert_eq!(it.peek(), Some(c));
+        assert_eq!(it.next(), Some(c));
+    }
+    assert_eq!(it.next(), None);
+
+    let mut it = s.chars();
+    while let Some(c) = it.peek_back() {
+        assert_eq!(it.peek_back(), Some(c));
+        assert_eq!(it.next_back(), Some(c));
+    }
+    assert_eq!(it.next_back(), None);
+
+    // Peeking from either end leaves the other end alone.
+    let mut it = s.chars();
+    assert_eq!(it.peek(), Some('ศ'));
+    assert_eq!(it.peek_back(), Some('🦀'));
+    assert_eq!(it.as_str(), s);
+    assert_eq!(it.next_back(), Some('🦀'));
+    assert_eq!(it.peek(), Some('ศ'));
+    assert_eq!(it.peek_back(), Some('m'));
+
+    let mut it = "é".chars();
+    assert_eq!(it.peek(), Some('é'));
+    assert_eq!(it.peek_back(), Some('é'));
+    it.next();
+    assert_eq!(it.peek(), None);
+    assert_eq!(it.peek_back(), None);
+}
+
+#[test]
+fn test_chars_nth_back() {
+    let s = "ศไทย中华Việt Nam🦀";
+    let v: Vec<char> = s.chars().collect();
+    for n in 0..v.len() + 2 {
+        let mut it = s.chars();
+        assert_eq!(it.nth_back(n), v.len().checked_sub(n + 1).map(|i| v[i]));
+        let rest: String = v[..v.len().saturating_sub(n + 1)].iter().collect();
+        assert_eq!(it.as_str(), rest);
+    }
+
+    let mut it = s.chars();
+    assert_eq!(it.nth_back(1), Some('m'));
+    assert_eq!(it.as_str(), "ศไทย中华Việt Na");
+    assert_eq!(it.nth_back(4), Some('ệ'));
+    assert_eq!(it.as_str(), "ศไทย中华Vi");
+    assert_eq!(it.next(), Some('ศ'));
+    assert_eq!(it.nth_back(6), Some('ไ'));
+    assert_eq!(it.as_str(), "");
+    assert_eq!(it.nth_back(0), None);
+
+    // Skipping past the front empties the iterator.
+    let mut it = "a🦀b".chars();
+    assert_eq!(it.nth_back(3), None);
+    assert_eq!(it.as_str(), "");
+    assert_eq!(it.next(), None);
+}
+
 #[test]
 fn test_to_lowercase_rev_iterator() {
     let s = "AÖßÜ💩ΣΤΙΓΜΑΣǅﬁİ";
@@ -1324,6 +1742,41 @@ fn test_bytesator_last() {
     assert_eq!(b.last().unwrap(), 109)
 }
 
+#[test]
+fn test_bytesator_fold() {
+    use std::ops::ControlFlow;
+
+    let s = "ศไทย中华Việt Nam";
+    let expected: Vec<u8> = s.as_bytes().to_vec();
+
+    let v = s.bytes().fold(Vec::new(), |mut v, b| {
+        v.push(b);
+        v
+    });
+    assert_eq!(v, expected);
+    let v = s.bytes().rfold(Vec::new(), |mut v, b| {
+        v.push(b);
+        v
+    });
+    assert_eq!(v, expected.iter().rev().copied().collect::<Vec<_>>());
+
+    assert_eq!(s.bytes().map(u32::from).sum::<u32>(), expected.iter().map(|&b| b as u32).sum());
+    assert_eq!("".bytes().map(u32::from).sum::<u32>(), 0);
+
+    // `try_fold` stops at the first break and leaves the rest.
+    let until_space = |n: usize, b: u8| {
+        if b == b' ' { ControlFlow::Break(n) } else { ControlFlow::Continue(n + 1) }
+    };
+    let mut b = s.bytes();
+    let r = b.try_fold(0, until_space);
+    assert_eq!(r, ControlFlow::Break(24));
+    assert_eq!(b.collect::<Vec<_>>(), b"Nam");
+    let mut b = s.bytes();
+    let r = b.try_rfold(0, until_space);
+    assert_eq!(r, ControlFlow::Break(3));
+    assert_eq!(b.len(), 24);
+}
+
 #[test]
 fn test_char_indicesator() {
     let s = "ศไทย中华Việt Nam";
@@ -1366,6 +1819,177 @@ fn test_char_indices_last() {
     assert_eq!(it.last(), Some((27, 'm')));
 }
 
+#[test]
+fn test_char_indices_offset() {
+    let s = "a楽b𝄞c";
+    let n = s.chars().count();
+
+    // Drive every interleaving of `next` and `next_back` (one bit per step),
+    // running one step past exhaustion to check the fused state too.
+    for mask in 0u32..1 << (n + 1) {
+        let mut it = s.char_indices();
+        let (mut front, mut back) = (0, s.len());
+        for step in 0..=n {
+            assert!(it.offset() <= it.offset_back());
+            assert_eq!(it.offset(), front);
+            assert_eq!(it.offset_back(), back);
+            assert_eq!(it.as_str(), &s[front..back]);
+
+            if mask & (1 << step) == 0 {
+                match it.next() {
+                    Some((i, c)) => {
+                        assert_eq!(i, front);
+                        front += c.len_utf8();
+                    }
+                    None => assert_eq!(front, back),
+                }
+            } else {
+                match it.next_back() {
+                    Some((i, c)) => {
+                        back -= c.len_utf8();
+                        assert_eq!(i, back);
+                    }
+                    None => assert_eq!(front, back),
+                }
+            }
+        }
+        assert_eq!(it.offset(), it.offset_back());
+    }
+}
+
+#[test]
+fn test_char_indices_size_hint() {
+    for s in ["", "abc", "aé", "a楽b𝄞c", "𝄞𝄞", "ศไทย中华Việt Nam"] {
+        let mut it = s.char_indices();
+        loop {
+            let len = it.as_str().len();
+            assert_eq!(it.char_count_upper_bound(), len);
+            assert_eq!(it.size_hint(), ((len + 3) / 4, Some(len)));
+            assert_eq!(it.size_hint(), it.as_str().chars().size_hint());
+            let (lower, upper) = it.size_hint();
+            let remaining = it.clone().count();
+            assert!(lower <= remaining && remaining <= upper.unwrap());
+            if it.next().is_none() {
+                break;
+            }
+        }
+    }
+
+    // The hint isn't exact for multibyte characters.
+    let mut it = "𝄞𝄞".char_indices();
+    assert_eq!(it.size_hint(), (2, Some(8)));
+    it.next_back();
+    assert_eq!(it.size_hint(), (1, Some(4)));
+    assert_eq!(it.char_count_upper_bound(), 4);
+
+    let mut it = "a楽b𝄞c".char_indices();
+    assert_eq!(it.size_hint(), (3, Some(10)));
+    it.next();
+    it.next();
+    assert_eq!(it.size_hint(), (2, Some(6)));
+
+    // Nor is it for ASCII, which would need a scan to detect.
+    assert_eq!("abcdefgh".char_indices().size_hint(), (2, Some(8)));
+}
+
+#[test]
+fn test_char_boundary_indices() {
+    for s in ["", "a", "aé", "a楽b𝄞c"] {
+        let expected: Vec<usize> = (0..=s.len()).filter(|&i| s.is_char_boundary(i)).collect();
+        let v: Vec<usize> = s.char_boundary_indices().collect();
+        assert_eq!(v, expected);
+        let mut v: Vec<usize> = s.char_boundary_indices().rev().collect();
+        v.reverse();
+        assert_eq!(v, expected);
+        assert_eq!(s.char_boundary_indices().last(), Some(s.len()));
+
+        // Meeting in the middle yields both endpoints exactly once.
+        for k in 0..=expected.len() {
+            let mut it = s.char_boundary_indices();
+            let mut front: Vec<usize> = it.by_ref().take(k).collect();
+            let back: Vec<usize> = it.rev().collect();
+            front.extend(back.into_iter().rev());
+            assert_eq!(front, expected);
+        }
+    }
+
+    let mut it = "aé".char_boundary_indices();
+    assert_eq!(it.size_hint().1, Some(4));
+    assert_eq!(it.next_back(), Some(3));
+    assert_eq!(it.next(), Some(0));
+    assert_eq!(it.next_back(), Some(1));
+    assert_eq!(it.next(), None);
+    assert_eq!(it.next_back(), None);
+}
+
+#[test]
+fn test_char_index_of_byte() {
+    for s in ["", "a", "aé", "a楽b𝄞c", "𝄞𝄞"] {
+        let chars = s.chars().count();
+        for byte in 0..=s.len() + 1 {
+            let expected = s.is_char_boundary(byte).then(|| s[..byte].chars().count());
+            assert_eq!(s.char_index_of_byte(byte), expected);
+        }
+        for n in 0..=chars + 1 {
+            let expected = s.char_indices().map(|(i, _)| i).chain([s.len()]).nth(n);
+            assert_eq!(s.byte_of_char_index(n), expected);
+        }
+
+        // The two are inverses of each other on char boundaries.
+        for (n, (i, _)) in s.char_indices().enumerate() {
+            assert_eq!(s.char_index_of_byte(i), Some(n));
+            assert_eq!(s.byte_of_char_index(n), Some(i));
+        }
+        assert_eq!(s.char_index_of_byte(s.len()), Some(chars));
+        assert_eq!(s.byte_of_char_index(chars), Some(s.len()));
+    }
+
+    let s = "a楽b𝄞c";
+    assert_eq!(s.char_index_of_byte(4), Some(2));
+    assert_eq!(s.char_index_of_byte(2), None);
+    assert_eq!(s.char_index_of_byte(9), Some(4));
+    assert_eq!(s.char_index_of_byte(7), None);
+    assert_eq!(s.byte_of_char_index(3), Some(5));
+    assert_eq!(s.byte_of_char_index(usize::MAX), None);
+}
+
+#[test]
+fn test_char_indices_nth() {
+    let s = "a楽b𝄞c";
+    let v: Vec<(usize, char)> = s.char_indices().collect();
+
+    for n in 0..=v.len() + 1 {
+        let mut it = s.char_indices();
+        assert_eq!(it.nth(n), v.get(n).copied());
+        let front = v.get(n + 1).map_or(s.len(), |&(i, _)| i);
+        assert_eq!(it.offset(), front);
+        assert_eq!(it.offset_back(), s.len());
+        assert_eq!(it.next(), v.get(n + 1).copied());
+
+        let mut it = s.char_indices();
+        let expected = v.len().checked_sub(n + 1).map(|i| v[i]);
+        assert_eq!(it.nth_back(n), expected);
+        assert_eq!(it.offset(), 0);
+        assert_eq!(it.offset_back(), expected.map_or(0, |(i, _)| i));
+        assert_eq!(it.as_str(), &s[..it.offset_back()]);
+    }
+
+    let mut it = s.char_indices();
+    assert_eq!(it.next(), Some((0, 'a')));
+    assert_eq!(it.nth_back(1), Some((5, '𝄞')));
+    assert_eq!(it.nth(1), Some((4, 'b')));
+    assert_eq!(it.nth(0), None);
+    assert_eq!(it.offset(), it.offset_back());
+}
+
+#[test]
+fn test_char_indices_nth_back_multibyte() {
+    let mut it = "a楽b".char_indices();
+    assert_eq!(it.nth_back(1), Some((1, '楽')));
+    assert_eq!(it.offset_back(), 1);
+    assert_eq!(it.next_back(), Some((0, 'a')));
+}
+
 #[test]
 fn test_splitn_char_iterator() {
     let data = "\nMäry häd ä little lämb\nLittle lämb\n";
@@ -1438,6 +2062,283 @@ fn test_split_char_iterator_inclusive_rev() {
     assert_eq!(split, ["CaT", "TurtlE", "SharK", "SheeP"]);
 }
 
+#[test]
+fn test_split_inclusive_trailing_separator() {
+    // A final separator ends the last piece instead of starting an empty
+    // one, as with `split_terminator`.
+    let split: Vec<&str> = "a\nb\n".split_inclusive('\n').collect();
+    assert_eq!(split, ["a\n", "b\n"]);
+    let split: Vec<&str> = "a\nb".split_inclusive('\n').collect();
+    assert_eq!(split, ["a\n", "b"]);
+    let split: Vec<&str> = "a\n\n".split_inclusive('\n').collect();
+    assert_eq!(split, ["a\n", "\n"]);
+    let split: Vec<&str> = "\n".split_inclusive('\n').collect();
+    assert_eq!(split, ["\n"]);
+    assert_eq!("".split_inclusive('\n').next(), None);
+
+    let split: Vec<&str> = "a\nb\n".split_inclusive('\n').rev().collect();
+    assert_eq!(split, ["b\n", "a\n"]);
+    let split: Vec<&str> = "a\nb".split_inclusive('\n').rev().collect();
+    assert_eq!(split, ["b", "a\n"]);
+    let split: Vec<&str> = "a\n\n".split_inclusive('\n').rev().collect();
+    assert_eq!(split, ["\n", "a\n"]);
+    assert_eq!("".split_inclusive('\n').next_back(), None);
+
+    // The same holds for multi-byte separators.
+    let split: Vec<&str> = "a::b::".split_inclusive("::").collect();
+    assert_eq!(split, ["a::", "b::"]);
+    let split: Vec<&str> = "a::b".split_inclusive("::").collect();
+    assert_eq!(split, ["a::", "b"]);
+}
+
+#[test]
+fn test_split_inclusive_position_rfold() {
+    // Compare against iterators that only expose `next` and `next_back`, so
+    // that the default `position` and `try_rfold` are used.
+    let data = "\nMäry häd ä little lämb\nLittle lämb\n";
+    let preds: [fn(&str) -> bool; 4] =
+        [|s| s.starts_with('L'), |s| s == "\n", |s| s.is_empty(), |s| s.contains('ä')];
+    for pred in preds {
+        let mut split = data.split_inclusive('\n');
+        let mut naive = data.split_inclusive('\n');
+        let mut naive = std::iter::from_fn(|| naive.next());
+        assert_eq!(split.position(pred), naive.position(pred));
+        assert_eq!(split.collect::<Vec<_>>(), naive.collect::<Vec<_>>());
+
+        let mut split = data.split_inclusive_retain_empty('\n');
+        let mut naive = data.split_inclusive_retain_empty('\n');
+        let mut naive = std::iter::from_fn(|| naive.next());
+        assert_eq!(split.position(pred), naive.position(pred));
+        assert_eq!(split.collect::<Vec<_>>(), naive.collect::<Vec<_>>());
+
+        let mut split = data.split_inclusive('\n');
+        let mut naive = data.split_inclusive('\n');
+        let mut naive = std::iter::from_fn(|| naive.next_back());
+        assert_eq!(split.rfind(|s| pred(s)), naive.find(|s| pred(s)));
+        assert_eq!(split.rev().collect::<Vec<_>>(), naive.collect::<Vec<_>>());
+
+        let mut split = data.split_inclusive_retain_empty('\n');
+        let mut naive = data.split_inclusive_retain_empty('\n');
+        let mut naive = std::iter::from_fn(|| naive.next_back());
+        assert_eq!(split.rev().position(pred), naive.position(pred));
+        assert_eq!(split.rev().collect::<Vec<_>>(), naive.collect::<Vec<_>>());
+    }
+
+    let mut split = "a\nb\nc".split_inclusive('\n');
+    assert_eq!(split.next(), Some("a\n"));
+    assert_eq!(split.position(|s| s == "c"), Some(1));
+    assert_eq!(split.next(), None);
+    let mut split = "a\nb\nc\n".split_inclusive('\n');
+    assert_eq!(split.next_back(), Some("c\n"));
+    assert_eq!(split.rfind(|s| s.starts_with('a')), Some("a\n"));
+    assert_eq!(split.next_back(), None);
+}
+
+#[test]
+fn test_split_inclusive_retain_empty() {
+    // With a trailing terminator, only the retaining variant yields an empty
+    // last piece.
+    let data = "\nMäry häd ä little lämb\nLittle lämb\n";
+    let split: Vec<&str> = data.split_inclusive_retain_empty('\n').collect();
+    assert_eq!(split, ["\n", "Märy häd ä little lämb\n", "Little lämb\n", ""]);
+    let split: Vec<&str> = data.split_inclusive_retain_empty('\n').rev().collect();
+    assert_eq!(split, ["", "Little lämb\n", "Märy häd ä little lämb\n", "\n"]);
+    let split: Vec<&str> = data.split_inclusive('\n').collect();
+    assert_eq!(split, ["\n", "Märy häd ä little lämb\n", "Little lämb\n"]);
+
+    // Without one, both variants agree.
+    let data = "a\nb";
+    let split: Vec<&str> = data.split_inclusive_retain_empty('\n').collect();
+    assert_eq!(split, ["a\n", "b"]);
+    let split: Vec<&str> = data.split_inclusive_retain_empty('\n').rev().collect();
+    assert_eq!(split, ["b", "a\n"]);
+    let split: Vec<&str> = data.split_inclusive('\n').collect();
+    assert_eq!(split, ["a\n", "b"]);
+
+    let split: Vec<&str> = "a::b::".split_inclusive_retain_empty("::").collect();
+    assert_eq!(split, ["a::", "b::", ""]);
+    let split: Vec<&str> = "\n".split_inclusive_retain_empty('\n').collect();
+    assert_eq!(split, ["\n", ""]);
+    let split: Vec<&str> = "".split_inclusive_retain_empty('\n').collect();
+    assert_eq!(split, [""]);
+    let split: Vec<&str> = "".split_inclusive('\n').collect();
+    assert!(split.is_empty());
+
+    let mut split = "a\n".split_inclusive_retain_empty('\n');
+    assert_eq!(split.next(), Some("a\n"));
+    assert_eq!(split.as_str(), "");
+    assert_eq!(split.next(), Some(""));
+    assert_eq!(split.next(), None);
+}
+
+#[test]
+fn test_split_str_iterator_inclusive() {
+    let split: Vec<&str> = "a::b::".split_inclusive("::").collect();
+    assert_eq!(split, ["a::", "b::"]);
+    let split: Vec<&str> = "a::b::".split_inclusive("::").rev().collect();
+    assert_eq!(split, ["b::", "a::"]);
+
+    let split: Vec<&str> = "::a::::b".split_inclusive("::").collect();
+    assert_eq!(split, ["::", "a::", "::", "b"]);
+    let split: Vec<&str> = "::a::::b".split_inclusive("::").rev().collect();
+    assert_eq!(split, ["b", "::", "a::", "::"]);
+
+    let data = "\nMäry häd ä little lämb\nLittle lämb\n";
+    let split: Vec<&str> = data.split_inclusive("lämb").collect();
+    assert_eq!(split, ["\nMäry häd ä little lämb", "\nLittle lämb", "\n"]);
+    let split: Vec<&str> = data.split_inclusive("lämb").rev().collect();
+    assert_eq!(split, ["\n", "\nLittle lämb", "\nMäry häd ä little lämb"]);
+
+    let mut split = "a::b::c".split_inclusive("::");
+    assert_eq!(split.next(), Some("a::"));
+    assert_eq!(split.next_back(), Some("c"));
+    assert_eq!(split.next(), Some("b::"));
+    assert_eq!(split.next_back(), None);
+    assert_eq!(split.next(), None);
+}
+
+#[test]
+fn test_split_inclusive_coalesced() {
+    let v: Vec<&str> = "a,,b".split_inclusive_coalesced(',').collect();
+    assert_eq!(v, ["a,,", "b"]);
+    let v: Vec<&str> = ",,a,,".split_inclusive_coalesced(',').collect();
+    assert_eq!(v, [",,", "a,,"]);
+    let v: Vec<&str> = ",,,".split_inclusive_coalesced(',').collect();
+    assert_eq!(v, [",,,"]);
+    let v: Vec<&str> = "abc".split_inclusive_coalesced(',').collect();
+    assert_eq!(v, ["abc"]);
+    let v: Vec<&str> = "".split_inclusive_coalesced(',').collect();
+    assert!(v.is_empty());
+
+    let v: Vec<&str> = "a--b----c-".split_inclusive_coalesced("--").collect();
+    assert_eq!(v, ["a--", "b----", "c-"]);
+    let v: Vec<&str> = "aé, éb".split_inclusive_coalesced(|c: char| !c.is_alphabetic()).collect();
+    assert_eq!(v, ["aé, ", "éb"]);
+    let v: Vec<&str> = "ab".split_inclusive_coalesced("").collect();
+    assert_eq!(v, ["a", "b"]);
+}
+
+#[test]
+fn test_split_on_any_indexed() {
+    let seps = &[',', ';', '→'];
+    let v: Vec<_> = "a,b;c".split_on_any_indexed(seps).collect();
+    assert_eq!(v, [("a", Some(',')), ("b", Some(';')), ("c", None)]);
+    let v: Vec<_> = "a,b;c".split_on_any_indexed(seps).rev().collect();
+    assert_eq!(v, [("c", None), ("b", Some(';')), ("a", Some(','))]);
+
+    // adjacent different separators, and separators at both ends
+    let v: Vec<_> = ";a,;→b,".split_on_any_indexed(seps).collect();
+    assert_eq!(
+        v,
+        [
+            ("", Some(';')),
+            ("a", Some(',')),
+            ("", Some(';')),
+            ("", Some('→')),
+            ("b", Some(',')),
+            ("", None),
+        ]
+    );
+    let mut rev: Vec<_> = ";a,;→b,".split_on_any_indexed(seps).rev().collect();
+    rev.reverse();
+    assert_eq!(rev, v);
+
+    let mut iter = "ä→ö,ü".split_on_any_indexed(seps);
+    assert_eq!(iter.next_back(), Some(("ü", None)));
+    assert_eq!(iter.next(), Some(("ä", Some('→'))));
+    assert_eq!(iter.next_back(), Some(("ö", Some(','))));
+    assert_eq!(iter.next(), None);
+
+    let v: Vec<_> = "abc".split_on_any_indexed(seps).collect();
+    assert_eq!(v, [("abc", None)]);
+    let v: Vec<_> = "".split_on_any_indexed(seps).collect();
+    assert_eq!(v, [("", None)]);
+    let v: Vec<_> = "a,b".split_on_any_indexed(&[]).collect();
+    assert_eq!(v, [("a,b", None)]);
+}
+
+#[test]
+fn test_char_set() {
+    let set = &[',', ';', '|'];
+    for s in ["a,b;c|d", ",;|", "", "ab", "ä;老|"] {
+        let v: Vec<&str> = s.split_on_char_set(set).collect();
+        assert_eq!(v, s.split(set).collect::<Vec<_>>());
+        let v: Vec<&str> = s.split_on_char_set(set).rev().collect();
+        assert_eq!(v, s.rsplit(set).collect::<Vec<_>>());
+
+        let v: Vec<(usize, char)> = s.match_indices_char_set(set).collect();
+        let expected: Vec<(usize, char)> =
+            s.char_indices().filter(|(_, c)| set.contains(c)).collect();
+        assert_eq!(v, expected);
+        let v: Vec<(usize, char)> = s.match_indices_char_set(set).rev().collect();
+        assert_eq!(v, expected.into_iter().rev().collect::<Vec<_>>());
+    }
+
+    let v: Vec<_> = "a,b;c|d".match_indices_char_set(set).collect();
+    assert_eq!(v, [(1, ','), (3, ';'), (5, '|')]);
+    let v: Vec<_> = "ä;老|".match_indices_char_set(&['老', '|']).collect();
+    assert_eq!(v, [(3, '老'), (6, '|')]);
+    assert_eq!("abc".match_indices_char_set(&[]).next(), None);
+}
+
+#[test]
+fn test_split_with_separators() {
+    let v: Vec<_> = "a, b,c".split_with_separators(',').collect();
+    assert_eq!(v, [("a", Some(",")), (" b", Some(",")), ("c", None)]);
+
+    // Multi-char separators are kept whole, and adjacent or trailing
+    // separators pair with empty pieces.
+    let v: Vec<_> = "a->->b->".split_with_separators("->").collect();
+    assert_eq!(v, [("a", Some("->")), ("", Some("->")), ("b", Some("->")), ("", None)]);
+    let v: Vec<_> = "x1y22".split_with_separators(char::is_numeric).collect();
+    assert_eq!(v, [("x", Some("1")), ("y", Some("2")), ("", Some("2")), ("", None)]);
+
+    let v: Vec<_> = "abc".split_with_separators(',').collect();
+    assert_eq!(v, [("abc", None)]);
+    let v: Vec<_> = "".split_with_separators(',').collect();
+    assert_eq!(v, [("", None)]);
+
+    for (s, pat) in [("", ","), ("a, b,c", ","), (",,", ","), ("Märy häd ä", "ä"), ("ab", "")] {
+        let pieces: Vec<&str> = s.split(pat).collect();
+        let mut rebuilt = String::new();
+        let mut n = 0;
+        for (piece, separator) in s.split_with_separators(pat) {
+            assert_eq!(piece, pieces[n]);
+            assert_eq!(separator.is_none(), n == pieces.len() - 1);
+            rebuilt.push_str(piece);
+            rebuilt.push_str(separator.unwrap_or(""));
+            n += 1;
+        }
+        assert_eq!(n, pieces.len());
+        assert_eq!(rebuilt, s);
+    }
+
+    let mut iter = "a,b".split_with_separators(',');
+    assert_eq!(iter.by_ref().count(), 2);
+    assert_eq!(iter.next(), None);
+}
+
+#[test]
+fn test_split_inclusive_as_str_rev() {
+    let mut split = "a\nb\nc\n".split_inclusive('\n');
+    assert_eq!(split.as_str(), "a\nb\nc\n");
+    assert_eq!(split.next_back(), Some("c\n"));
+    assert_eq!(split.as_str(), "a\nb\n");
+    assert_eq!(split.next_back(), Some("b\n"));
+    assert_eq!(split.as_str(), "a\n");
+    assert_eq!(split.next_back(), Some("a\n"));
+    assert_eq!(split.as_str(), "");
+
+    // Consuming from both ends leaves the middle.
+    let mut split = "a\nb\nc\n".split_inclusive('\n');
+    assert_eq!(split.next(), Some("a\n"));
+    assert_eq!(split.next_back(), Some("c\n"));
+    assert_eq!(split.as_str(), "b\n");
+    assert_eq!(split.next_back(), Some("b\n"));
+    assert_eq!(split.as_str(), "");
+    assert_eq!(split.next(), None);
+}
+
 #[test]
 fn test_rsplit() {
     let data = "\nMäry häd ä little lämb\nLittle lämb\n";
@@ -1452,6 +2353,255 @@ fn test_rsplit() {
     assert_eq!(split, ["mb\n", "mb\nLittle l", " little l", "d ", "ry h", "\nM"]);
 }
 
+#[test]
+fn test_rsplit_as_str() {
+    let mut split = "a.b.c.d".rsplit('.');
+    assert_eq!(split.as_str(), "a.b.c.d");
+    assert_eq!(split.next(), Some("d"));
+    assert_eq!(split.as_str(), "a.b.c");
+    assert_eq!(split.next_back(), Some("a"));
+    assert_eq!(split.as_str(), "b.c");
+    assert_eq!(split.next(), Some("c"));
+    assert_eq!(split.as_str(), "b");
+    assert_eq!(split.next_back(), Some("b"));
+    assert_eq!(split.as_str(), "");
+    assert_eq!(split.next(), None);
+    assert_eq!(split.next_back(), None);
+    assert_eq!(split.as_str(), "");
+
+    // Every interleaving of `next` and `next_back`, including empty pieces,
+    // leaves exactly the unyielded pieces in the middle.
+    for s in ["a.b.c.d", "a..b.", ".", ""] {
+        for mask in 0u32..1 << 5 {
+            let mut split = s.rsplit('.');
+            let mut remaining: Vec<&str> = s.split('.').collect();
+            for step in 0..5 {
+                let piece = if mask & (1 << step) == 0 {
+                    split.next().map(|p| (p, remaining.pop().unwrap()))
+                } else {
+                    split.next_back().map(|p| (p, remaining.remove(0)))
+                };
+                if let Some((got, expected)) = piece {
+                    assert_eq!(got, expected);
+                }
+                assert_eq!(split.as_str(), remaining.join("."));
+            }
+            assert!(remaining.is_empty());
+        }
+    }
+}
+
+#[test]
+fn test_split_nth() {
+    let mut split = "a-b-c-d".rsplit('-');
+    assert_eq!(split.nth(1), Some("c"));
+    assert_eq!(split.as_str(), "a-b");
+    assert_eq!(split.nth(1), Some("a"));
+    assert_eq!(split.as_str(), "");
+    assert_eq!(split.nth(0), None);
+
+    fn naive_nth<'a>(iter: &mut impl Iterator<Item = &'a str>, n: usize) -> Option<&'a str> {
+        for _ in 0..n {
+            iter.next()?;
+        }
+        iter.next()
+    }
+
+    // Compare against repeatedly calling `next`, including the skipped
+    // trailing empty piece of the terminator variants.
+    macro_rules! check {
+        ($method:ident, $pat:expr) => {
+            for s in ["a-b-c-d", "-a--b-", "-", "", "a::b-c::"] {
+                for n in 0..4 {
+                    let mut split = s.$method($pat);
+                    let mut naive = s.$method($pat);
+                    for _ in 0..3 {
+                        assert_eq!(split.nth(n), naive_nth(&mut naive, n));
+                        assert_eq!(split.as_str(), naive.as_str());
+                    }
+                    assert_eq!(split.collect::<Vec<_>>(), naive.collect::<Vec<_>>());
+                }
+            }
+        };
+    }
+    check!(split, '-');
+    check!(rsplit, '-');
+    check!(split_terminator, '-');
+    check!(rsplit_terminator, '-');
+    check!(split, "::");
+    check!(rsplit, "::");
+    check!(rsplit_terminator, "::");
+}
+
+#[test]
+fn test_rsplit_terminator_as_str() {
+    let mut split = "A..B..".rsplit_terminator('.');
+    assert_eq!(split.as_str(), "A..B..");
+    assert_eq!(split.next(), Some(""));
+    assert_eq!(split.as_str(), "A..B");
+    assert_eq!(split.next_back(), Some("A"));
+    assert_eq!(split.as_str(), ".B");
+    assert_eq!(split.next(), Some("B"));
+    assert_eq!(split.as_str(), "");
+    assert_eq!(split.next_back(), Some(""));
+    assert_eq!(split.as_str(), "");
+    assert_eq!(split.next(), None);
+    assert_eq!(split.next_back(), None);
+    assert_eq!(split.as_str(), "");
+
+    // Until the trailing empty piece has been skipped from the back, the
+    // remainder still ends with its terminator. Afterwards it is exactly the
+    // unyielded pieces.
+    for s in ["A..B..", "A..B", "..", ".", ""] {
+        for mask in 0u32..1 << 5 {
+            let mut split = s.rsplit_terminator('.');
+            let mut remaining: Vec<&str> = s.split_terminator('.').collect();
+            let mut skipped = false;
+            for step in 0..5 {
+                let piece = if mask & (1 << step) == 0 {
+                    skipped = true;
+                    split.next().map(|p| (p, remaining.pop().unwrap()))
+                } else {
+                    split.next_back().map(|p| (p, remaining.remove(0)))
+                };
+                if let Some((got, expected)) = piece {
+                    assert_eq!(got, expected);
+                }
+                let mut middle = remaining.join(".");
+                if !skipped && !remaining.is_empty() && s.ends_with('.') {
+                    middle.push('.');
+                }
+                assert_eq!(split.as_str(), middle);
+            }
+            assert!(remaining.is_empty());
+        }
+    }
+}
+
+#[test]
+fn test_split_empty_pattern() {
+    // The empty pattern matches at every char boundary, including both ends
+    // of the string, so each piece between two matches is a single char.
+    let s = "aé😀";
+    let v: Vec<&str> = s.split("").collect();
+    assert_eq!(v, ["", "a", "é", "😀", ""]);
+    let v: Vec<&str> = s.rsplit("").collect();
+    assert_eq!(v, ["", "😀", "é", "a", ""]);
+
+    // Only the final empty piece is treated as a terminator.
+    let v: Vec<&str> = s.split_terminator("").collect();
+    assert_eq!(v, ["", "a", "é", "😀"]);
+    let v: Vec<&str> = s.rsplit_terminator("").collect();
+    assert_eq!(v, ["😀", "é", "a", ""]);
+
+    // Each piece ends with its zero-width match, so the match at the start
+    // ends an empty first piece, and the one at the end closes the last char.
+    let v: Vec<&str> = s.split_inclusive("").collect();
+    assert_eq!(v, ["", "a", "é", "😀"]);
+
+    let v: Vec<&str> = s.splitn(3, "").collect();
+    assert_eq!(v, ["", "a", "é😀"]);
+    let v: Vec<&str> = s.rsplitn(3, "").collect();
+    assert_eq!(v, ["", "😀", "aé"]);
+    assert_eq!(s.split_once(""), Some(("", s)));
+    assert_eq!(s.rsplit_once(""), Some((s, "")));
+
+    let v: Vec<_> = s.split_ranges("").collect();
+    assert_eq!(v, [0..0, 0..1, 1..3, 3..7, 7..7]);
+
+    // The empty string still holds a single match.
+    let v: Vec<&str> = "".split("").collect();
+    assert_eq!(v, ["", ""]);
+    let v: Vec<&str> = "".rsplit("").collect();
+    assert_eq!(v, ["", ""]);
+    let v: Vec<&str> = "".split_terminator("").collect();
+    assert_eq!(v, [""]);
+    let v: Vec<&str> = "".rsplit_terminator("").collect();
+    assert_eq!(v, [""]);
+    let v: Vec<&str> = "".split_inclusive("").collect();
+    assert_eq!(v, [""]);
+    let v: Vec<_> = "".split_ranges("").collect();
+    assert_eq!(v, [0..0, 0..0]);
+
+    // `as_str` reports the unsplit remainder after the yielded pieces.
+    let mut split = "ab".split("");
+    assert_eq!(split.next(), Some(""));
+    assert_eq!(split.as_str(), "ab");
+    assert_eq!(split.next(), Some("a"));
+    assert_eq!(split.as_str(), "b");
+    assert_eq!(split.next(), Some("b"));
+    assert_eq!(split.as_str(), "");
+    assert_eq!(split.next(), Some(""));
+    assert_eq!(split.as_str(), "");
+    assert_eq!(split.next(), None);
+}
+
+#[test]
+fn test_split_empty_pattern_size_hint() {
+    fn check<'a, I: Iterator<Item = &'a str> + Clone>(mut it: I) {
+        loop {
+            let (lower, upper) = it.size_hint();
+            let remaining = it.clone().count();
+            assert!(lower <= remaining && remaining <= upper.unwrap());
+            if it.next().is_none() {
+                assert_eq!(it.size_hint(), (0, Some(0)));
+                break;
+            }
+        }
+    }
+
+    for s in ["", "a", "ab", "aé😀", "日本語", "abcdefgh"] {
+        let len = s.len();
+        assert_eq!(s.split("").size_hint(), ((len + 3) / 4 + 2, Some(len + 2)));
+        assert_eq!(s.rsplit("").size_hint(), ((len + 3) / 4 + 2, Some(len + 2)));
+        assert_eq!(s.split_terminator("").size_hint(), ((len + 3) / 4 + 1, Some(len + 1)));
+        assert_eq!(s.rsplit_terminator("").size_hint(), ((len + 3) / 4 + 1, Some(len + 1)));
+
+        check(s.split(""));
+        check(s.rsplit(""));
+        check(s.split_terminator(""));
+        check(s.rsplit_terminator(""));
+    }
+
+    // The lower bound is reached for a string of four-byte chars.
+    assert_eq!("😀😀".split("").size_hint(), (4, Some(10)));
+    assert_eq!("😀😀".split("").count(), 4);
+
+    // Non-empty needles only know when they are done.
+    let mut split = "a,b".split(",");
+    assert_eq!(split.size_hint(), (0, None));
+    split.by_ref().for_each(drop);
+    assert_eq!(split.size_hint(), (0, Some(0)));
+}
+
+#[test]
+fn test_split_ranges() {
+    let v: Vec<_> = "a,bb,c".split_ranges(',').collect();
+    assert_eq!(v, [0..1, 2..4, 5..6]);
+    let v: Vec<_> = ",a,,b,".split_ranges(',').collect();
+    assert_eq!(v, [0..0, 1..2, 3..3, 4..5, 6..6]);
+    let v: Vec<_> = "".split_ranges(',').collect();
+    assert_eq!(v, [0..0]);
+    let v: Vec<_> = "ä,ö".split_ranges(',').collect();
+    assert_eq!(v, [0..2, 3..5]);
+
+    let v: Vec<_> = "a,bb,c".split_ranges(',').rev().collect();
+    assert_eq!(v, [5..6, 2..4, 0..1]);
+    let v: Vec<_> = "ä,ö".rsplit_ranges(',').collect();
+    assert_eq!(v, [3..5, 0..2]);
+
+    // Forward and reverse searches for `&str` patterns can differ.
+    let v: Vec<_> = "aXXbXXXc".split_ranges("XX").collect();
+    assert_eq!(v, [0..1, 3..4, 6..8]);
+    let v: Vec<_> = "aXXbXXXc".rsplit_ranges("XX").collect();
+    assert_eq!(v, [7..8, 3..5, 0..1]);
+
+    let data = "\nMäry häd ä little lämb\nLittle lämb\n";
+    let pieces: Vec<&str> = data.split(' ').collect();
+    let ranges: Vec<&str> = data.split_ranges(' ').map(|r| &data[r]).collect();
+    assert_eq!(pieces, ranges);
+}
+
 #[test]
 fn test_rsplitn() {
     let data = "\nMäry häd ä little lämb\nLittle lämb\n";
@@ -1466,6 +2616,304 @@ fn test_rsplitn() {
     assert_eq!(split, ["mb\n", "\nMäry häd ä little lämb\nLittle l"]);
 }
 
+#[test]
+fn test_split_max_seps() {
+    let data = "\nMäry häd ä little lämb\nLittle lämb\n";
+
+    for n in 0..6 {
+        let split: Vec<&str> = data.split_max_seps(n, ' ').collect();
+        assert_eq!(split, data.splitn(n + 1, ' ').collect::<Vec<_>>());
+        let split: Vec<&str> = data.rsplit_max_seps(n, "lämb").collect();
+        assert_eq!(split, data.rsplitn(n + 1, "lämb").collect::<Vec<_>>());
+    }
+
+    let split: Vec<&str> = data.split_max_seps(0, ' ').collect();
+    assert_eq!(split, [data]);
+    let split: Vec<&str> = data.split_max_seps(2, ' ').collect();
+    assert_eq!(split, ["\nMäry", "häd", "ä little lämb\nLittle lämb\n"]);
+    let split: Vec<&str> = data.rsplit_max_seps(1, ' ').collect();
+    assert_eq!(split, ["lämb\n", "\nMäry häd ä little lämb\nLittle"]);
+
+    // trailing separators still produce a trailing empty piece
+    let split: Vec<&str> = "a,b,".split_max_seps(5, ',').collect();
+    assert_eq!(split, ["a", "b", ""]);
+    let split: Vec<&str> = ",a,b".rsplit_max_seps(5, ',').collect();
+    assert_eq!(split, ["b", "a", ""]);
+
+    let split: Vec<&str> = "a,b".split_max_seps(usize::MAX, ',').collect();
+    assert_eq!(split, ["a", "b"]);
+}
+
+#[test]
+fn test_split_pairs() {
+    let v: Vec<_> = "a,b,c".split(',').pairs().collect();
+    assert_eq!(v, [("a", "b"), ("b", "c")]);
+
+    // a single piece has no neighbour
+    assert_eq!("abc".split(',').pairs().next(), None);
+    assert_eq!("".split(',').pairs().next(), None);
+
+    // empty pieces are paired like any other
+    let v: Vec<_> = ",a,,".split(',').pairs().collect();
+    assert_eq!(v, [("", "a"), ("a", ""), ("", "")]);
+
+    let v: Vec<_> = "Märy häd ä lämb".split(' ').pairs().collect();
+    assert_eq!(v, [("Märy", "häd"), ("häd", "ä"), ("ä", "lämb")]);
+
+    let mut pairs = "a,b".split(',').pairs();
+    assert_eq!(pairs.next(), Some(("a", "b")));
+    assert_eq!(pairs.next(), None);
+    assert_eq!(pairs.next(), None);
+}
+
+#[test]
+fn test_split_with_offsets() {
+    let v: Vec<_> = "a,bb,c".split(',').with_offsets().collect();
+    assert_eq!(v, [(0, "a"), (2, "bb"), (5, "c")]);
+
+    // empty pieces get offsets too
+    let v: Vec<_> = ",a,,".split(',').with_offsets().collect();
+    assert_eq!(v, [(0, ""), (1, "a"), (3, ""), (4, "")]);
+    let v: Vec<_> = "".split(',').with_offsets().collect();
+    assert_eq!(v, [(0, "")]);
+
+    let v: Vec<_> = ",a,,".split(',').with_offsets().rev().collect();
+    assert_eq!(v, [(4, ""), (3, ""), (1, "a"), (0, "")]);
+
+    let v: Vec<_> = "Märy häd ä lämb".split(' ').with_offsets().enumerate().collect();
+    assert_eq!(v, [(0, (0, "Märy")), (1, (6, "häd")), (2, (11, "ä")), (3, (14, "lämb"))]);
+
+    let v: Vec<_> = "a::b::c".split("::").with_offsets().collect();
+    assert_eq!(v, [(0, "a"), (3, "b"), (6, "c")]);
+
+    let mut iter = "a:b:c".split(':').with_offsets();
+    assert_eq!(iter.next(), Some((0, "a")));
+    assert_eq!(iter.next_back(), Some((4, "c")));
+    assert_eq!(iter.next(), Some((2, "b")));
+    assert_eq!(iter.next_back(), None);
+}
+
+#[test]
+fn test_split_try_fold() {
+    use std::ops::ControlFlow;
+
+    fn check<'a, I: Iterator<Item = &'a str> + Clone>(iter: I) {
+        let expected: Vec<&str> = iter.clone().collect();
+        let mut folded = iter.clone();
+        let actual = folded.try_fold(Vec::new(), |mut v, s| {
+            v.push(s);
+            ControlFlow::<(), _>::Continue(v)
+        });
+        assert_eq!(actual, ControlFlow::Continue(expected.clone()));
+        assert_eq!(folded.next(), None);
+
+        // Stop after each possible number of items, then resume with `next`.
+        for n in 0..expected.len() {
+            let mut iter = iter.clone();
+            let mut i = 0;
+            let r = iter.try_fold((), |(), s| {
+                assert_eq!(s, expected[i]);
+                if i == n {
+                    return ControlFlow::Break(i);
+                }
+                i += 1;
+                ControlFlow::Continue(())
+            });
+            assert_eq!(r, ControlFlow::Break(n));
+            assert_eq!(iter.collect::<Vec<_>>(), expected[n + 1..]);
+        }
+    }
+
+    for data in ["", ".", "..", "A..B..", ".A.B", "Märy.häd..ä.lämb.", "no separator"] {
+        check(data.split('.'));
+        check(data.rsplit('.'));
+        check(data.split_terminator('.'));
+        check(data.rsplit_terminator('.'));
+        check(data.split(".."));
+        check(data.rsplit(".."));
+        check(data.split_terminator(".."));
+        check(data.rsplit_terminator(".."));
+
+        // `try_fold` after consuming from the other end.
+        let mut split = data.split_terminator('.');
+        split.next_back();
+        check(split);
+        let mut split = data.rsplit_terminator('.');
+        split.next_back();
+        check(split);
+    }
+}
+
+#[test]
+fn test_split_char_matches_naive() {
+    // Splits `s` at every occurrence of `c` by walking its chars.
+    fn naive(s: &str, c: char) -> Vec<&str> {
+        let mut pieces = Vec::new();
+        let mut start = 0;
+        for (i, ch) in s.char_indices() {
+            if ch == c {
+                pieces.push(&s[start..i]);
+                start = i + c.len_utf8();
+            }
+        }
+        pieces.push(&s[start..]);
+        pieces
+    }
+
+    let long = "a,b".repeat(20) + ",";
+    let data = ["", ",", ",,", "a,b,,c,", "no separator", "ä,ö,,ü", "ä→ö→→", long.as_str()];
+    for s in data {
+        for c in [',', '→', 'ö', 'x'] {
+            let expected = naive(s, c);
+            assert_eq!(s.split(c).collect::<Vec<_>>(), expected);
+
+            let mut rev = expected.clone();
+            rev.reverse();
+            assert_eq!(s.rsplit(c).collect::<Vec<_>>(), rev);
+
+            // Only the trailing empty piece is skipped by the terminator
+            // variants, from either end.
+            let mut terminated = expected.clone();
+            if terminated.last() == Some(&"") {
+                terminated.pop();
+            }
+            assert_eq!(s.split_terminator(c).collect::<Vec<_>>(), terminated);
+            terminated.reverse();
+            assert_eq!(s.rsplit_terminator(c).collect::<Vec<_>>(), terminated);
+        }
+    }
+}
+
+#[test]
+fn test_split_fold() {
+    // The suppressed trailing empty piece must not be folded.
+    let sum: i32 = "1,2,3,".split_terminator(',').map(|s| s.parse::<i32>().unwrap()).sum();
+    assert_eq!(sum, 6);
+    let sum: i32 = "1,2,3,".rsplit_terminator(',').map(|s| s.parse::<i32>().unwrap()).sum();
+    assert_eq!(sum, 6);
+
+    for data in ["", ".", "..", "A..B..", ".A.B", "Märy.häd..ä.lämb."] {
+        let fold = |mut v: Vec<&'static str>, s| {
+            v.push(s);
+            v
+        };
+        assert_eq!(data.split('.').fold(Vec::new(), fold), data.split('.').collect::<Vec<_>>());
+        assert_eq!(data.rsplit('.').fold(Vec::new(), fold), data.rsplit('.').collect::<Vec<_>>());
+        assert_eq!(
+            data.split_terminator('.').fold(Vec::new(), fold),
+            data.split_terminator('.').collect::<Vec<_>>()
+        );
+        assert_eq!(
+            data.rsplit_terminator("..").fold(Vec::new(), fold),
+            data.rsplit_terminator("..").collect::<Vec<_>>()
+        );
+    }
+}
+
+#[test]
+fn test_split_collect() {
+    let long = "ab,c".repeat(100);
+    for s in ["", ",", "a,b,,c,", "no separator", "ä→ö→→", long.as_str()] {
+        let v = s.split_collect(',');
+        assert_eq!(v, s.split(',').collect::<Vec<_>>());
+        assert_eq!(v.capacity(), v.len());
+
+        let v = s.split_collect("→");
+        assert_eq!(v, s.split("→").collect::<Vec<_>>());
+        assert_eq!(v.capacity(), v.len());
+
+        let v = s.split_collect(|c: char| c == 'b' || c == 'ö');
+        assert_eq!(v, s.split(|c: char| c == 'b' || c == 'ö').collect::<Vec<_>>());
+        assert_eq!(v.capacity(), v.len());
+    }
+}
+
+#[test]
+fn test_split_find() {
+    let data = "ศไทย中华Việt Nam; Mary had a little lamb, Little lamb";
+    let preds: [fn(&str) -> bool; 4] =
+        [|s| s.is_empty(), |s| s.len() > 4, |s| s.starts_with('l'), |s| s.contains('z')];
+    for pred in preds {
+        assert_eq!(data.split_find(' ', pred), data.split(' ').find(|s| pred(s)));
+        assert_eq!(data.split_find("am", pred), data.split("am").find(|s| pred(s)));
+        assert_eq!(data.split_find(char::is_whitespace, pred), data.split(' ').find(|s| pred(s)));
+    }
+    assert_eq!("a,,b".split_find(',', str::is_empty), Some(""));
+    assert_eq!("a,b,".split_find(',', |s| s != "a" && s != "b"), Some(""));
+    assert_eq!("".split_find(',', str::is_empty), Some(""));
+
+    // The searcher isn't run past the end of the found piece.
+    let mut visited = String::new();
+    let found = "a,bb,c,dd".split_find(
+        |c: char| {
+            visited.push(c);
+            c == ','
+        },
+        |s: &str| s.len() == 2,
+    );
+    assert_eq!(found, Some("bb"));
+    assert_eq!(visited, "a,bb,");
+}
+
+#[test]
+fn test_split_extend() {
+    let data = "ศไทย中华Việt Nam; Mary had a little lamb, Little lamb";
+    let mut v = vec!["first"];
+    data.split_extend(' ', &mut v);
+    assert_eq!(v[0], "first");
+    assert_eq!(v[1..], data.split(' ').collect::<Vec<_>>());
+
+    let mut v = vec!["first"];
+    data.split_extend("am", &mut v);
+    data.split_extend(char::is_whitespace, &mut v);
+    let expected: Vec<&str> =
+        ["first"].into_iter().chain(data.split("am")).chain(data.split(' ')).collect();
+    assert_eq!(v, expected);
+
+    let mut v = vec!["x"];
+    "".split_extend(',', &mut v);
+    ",a,".split_extend(',', &mut v);
+    assert_eq!(v, ["x", "", "", "a", ""]);
+}
+
+#[test]
+fn test_split_at_boundaries() {
+    let camel = |a: char, b: char| a.is_lowercase() && b.is_uppercase();
+    let v: Vec<&str> = "fooBar".split_at_boundaries(camel).collect();
+    assert_eq!(v, ["foo", "Bar"]);
+    let v: Vec<&str> = "Märy".split_at_boundaries(camel).collect();
+    assert_eq!(v, ["Märy"]);
+
+    // Consecutive boundaries give single-char pieces.
+    let v: Vec<&str> = "aXbYY".split_at_boundaries(|a, b| a != b).collect();
+    assert_eq!(v, ["a", "X", "b", "YY"]);
+    let v: Vec<&str> = "ศไทย".split_at_boundaries(|_, _| true).collect();
+    assert_eq!(v, ["ศ", "ไ", "ท", "ย"]);
+
+    // A single char has no pairs to split between.
+    let v: Vec<&str> = "x".split_at_boundaries(|_, _| true).collect();
+    assert_eq!(v, ["x"]);
+    assert_eq!("".split_at_boundaries(|_, _| true).next(), None);
+
+    // The closure sees each adjacent pair once, in order.
+    let mut pairs = Vec::new();
+    let v: Vec<&str> = "abc"
+        .split_at_boundaries(|a, b| {
+            pairs.push((a, b));
+            false
+        })
+        .collect();
+    assert_eq!(v, ["abc"]);
+    assert_eq!(pairs, [('a', 'b'), ('b', 'c')]);
+
+    let mut iter = "ab".split_at_boundaries(|_, _| true);
+    assert_eq!(iter.size_hint(), (1, Some(2)));
+    assert_eq!(iter.next(), Some("a"));
+    assert_eq!(iter.next(), Some("b"));
+    assert_eq!(iter.size_hint(), (0, Some(0)));
+    assert_eq!(iter.next(), None);
+}
+
 #[test]
 fn test_split_once() {
     assert_eq!("".split_once("->"), None);
@@ -1490,6 +2938,28 @@ fn test_rsplit_once() {
     assert_eq!("---".rsplit_once("--"), Some(("-", "")));
 }
 
+#[test]
+fn test_match_piece() {
+    assert_eq!("".first_match_piece("->"), "");
+    assert_eq!("a-b".first_match_piece("->"), "a-b");
+    assert_eq!("a->".first_match_piece("->"), "a");
+    assert_eq!("->b".first_match_piece("->"), "");
+    assert_eq!("a->b->c".first_match_piece("->"), "a");
+    assert_eq!("---".first_match_piece("--"), "");
+
+    assert_eq!("".last_match_piece("->"), "");
+    assert_eq!("a-b".last_match_piece("->"), "a-b");
+    assert_eq!("a->".last_match_piece("->"), "");
+    assert_eq!("->b".last_match_piece("->"), "b");
+    assert_eq!("a->b->c".last_match_piece("->"), "c");
+    assert_eq!("---".last_match_piece("--"), "");
+
+    for s in ["", "a", "/", "a/b/", "/a/b", "a//b"] {
+        assert_eq!(s.first_match_piece('/'), s.split('/').next().unwrap());
+        assert_eq!(s.last_match_piece('/'), s.rsplit('/').next().unwrap());
+    }
+}
+
 #[test]
 fn test_split_whitespace() {
     let data = "\n \tMäry   häd\tä  little lämb\nLittle lämb\n";
@@ -1497,6 +2967,131 @@ fn test_split_whitespace() {
     assert_eq!(words, ["Märy", "häd", "ä", "little", "lämb", "Little", "lämb"])
 }
 
+#[test]
+fn test_split_whitespace_as_str_rev() {
+    let mut split = "a b c".split_whitespace();
+    assert_eq!(split.next_back(), Some("c"));
+    assert_eq!(split.as_str(), "a b");
+    assert_eq!(split.next(), Some("a"));
+    assert_eq!(split.as_str(), "b");
+    assert_eq!(split.next_back(), Some("b"));
+    assert_eq!(split.as_str(), "");
+    assert_eq!(split.next_back(), None);
+    assert_eq!(split.as_str(), "");
+
+    // Runs of whitespace skipped from the back are consumed along with the
+    // word that follows them.
+    let mut split = " a  b c\t\n".split_whitespace();
+    assert_eq!(split.next_back(), Some("c"));
+    assert_eq!(split.as_str(), " a  b");
+    assert_eq!(split.next_back(), Some("b"));
+    assert_eq!(split.as_str(), " a ");
+    assert_eq!(split.next(), Some("a"));
+    assert_eq!(split.as_str(), "");
+    assert_eq!(split.next(), None);
+}
+
+#[test]
+fn test_split_whitespace_indices() {
+    let v: Vec<_> = "  foo bar".split_whitespace_indices().collect();
+    assert_eq!(v, [(2, "foo"), (6, "bar")]);
+
+    let v: Vec<_> = "\tfoo  \n bar baz \n".split_whitespace_indices().collect();
+    assert_eq!(v, [(1, "foo"), (8, "bar"), (12, "baz")]);
+
+    // U+00A0 and U+2009 are multibyte whitespace
+    let data = "\u{a0}Märy\u{2009}häd\u{a0}\u{a0}ä";
+    let v: Vec<_> = data.split_whitespace_indices().collect();
+    assert_eq!(v, [(2, "Märy"), (10, "häd"), (18, "ä")]);
+    for (i, word) in v {
+        assert_eq!(&data[i..i + word.len()], word);
+    }
+
+    let v: Vec<_> = data.split_whitespace_indices().rev().collect();
+    assert_eq!(v, [(18, "ä"), (10, "häd"), (2, "Märy")]);
+
+    assert_eq!("".split_whitespace_indices().next(), None);
+    assert_eq!(" \u{a0} ".split_whitespace_indices().next(), None);
+}
+
+#[test]
+fn test_split_byte_chunks() {
+    let v: Vec<&str> = "abcdef".split_byte_chunks(3).collect();
+    assert_eq!(v, ["abc", "def"]);
+    let v: Vec<&str> = "abcdefg".split_byte_chunks(3).collect();
+    assert_eq!(v, ["abc", "def", "g"]);
+    let v: Vec<&str> = "abc".split_byte_chunks(10).collect();
+    assert_eq!(v, ["abc"]);
+    assert_eq!("".split_byte_chunks(1).next(), None);
+
+    // chunks never split a char
+    let v: Vec<&str> = "a楽b".split_byte_chunks(2).collect();
+    assert_eq!(v, ["a", "楽", "b"]);
+    let v: Vec<&str> = "a楽b".split_byte_chunks(4).collect();
+    assert_eq!(v, ["a楽", "b"]);
+    let v: Vec<&str> = "äöü".split_byte_chunks(3).collect();
+    assert_eq!(v, ["ä", "ö", "ü"]);
+    let v: Vec<&str> = "äöü".split_byte_chunks(4).collect();
+    assert_eq!(v, ["äö", "ü"]);
+
+    // chars longer than `max_len` are yielded alone
+    let v: Vec<&str> = "𝄞楽".split_byte_chunks(1).collect();
+    assert_eq!(v, ["𝄞", "楽"]);
+
+    let data = "Märy häd ä little lämb";
+    for max_len in 1..=data.len() {
+        let chunks: Vec<&str> = data.split_byte_chunks(max_len).collect();
+        assert_eq!(chunks.concat(), data);
+        let (lower, upper) = data.split_byte_chunks(max_len).size_hint();
+        assert!(lower <= chunks.len() && chunks.len() <= upper.unwrap());
+        for chunk in chunks {
+            assert!(chunk.len() <= max_len || chunk.chars().count() == 1);
+        }
+    }
+}
+
+#[test]
+#[should_panic]
+fn test_split_byte_chunks_zero() {
+    let _ = "abc".split_byte_chunks(0);
+}
+
+#[test]
+fn test_rsplit_at_whitespace() {
+    assert_eq!("a b   c  ".rsplit_at_whitespace(), Some(("a b", "c")));
+    assert_eq!("a b c".rsplit_at_whitespace(), Some(("a b", "c")));
+    assert_eq!(
+        "  Märy häd\t\n lämb \u{2009}\n".rsplit_at_whitespace(),
+        Some(("  Märy häd", "lämb"))
+    );
+
+    // single token
+    assert_eq!("abc".rsplit_at_whitespace(), Some(("", "abc")));
+    assert_eq!("  abc \t".rsplit_at_whitespace(), Some(("", "abc")));
+
+    // all whitespace
+    assert_eq!("".rsplit_at_whitespace(), None);
+    assert_eq!(" \t\u{2009}\n ".rsplit_at_whitespace(), None);
+}
+
+#[test]
+fn test_split_trimmed() {
+    let split: Vec<&str> = " a , b ,c ".split_trimmed(',').collect();
+    assert_eq!(split, ["a", "b", "c"]);
+
+    // Whitespace-only pieces are trimmed to empty strings, not skipped.
+    let split: Vec<&str> = "a,  ,\t\n, b".split_trimmed(',').collect();
+    assert_eq!(split, ["a", "", "", "b"]);
+    let split: Vec<&str> = "   ".split_trimmed(',').collect();
+    assert_eq!(split, [""]);
+
+    let split: Vec<&str> = " ä\u{2009}: ö ::ü".split_trimmed("::").collect();
+    assert_eq!(split, ["ä\u{2009}: ö", "ü"]);
+
+    let split: Vec<&str> = " a , b ,c ".split_trimmed(',').rev().collect();
+    assert_eq!(split, ["c", "b", "a"]);
+}
+
 #[test]
 fn test_lines() {
     let data = "\nMäry häd ä little lämb\n\r\nLittle lämb\n";
@@ -1508,6 +3103,159 @@ fn test_lines() {
     assert_eq!(lines, ["", "Märy häd ä little lämb", "", "Little lämb"]);
 }
 
+#[test]
+fn test_lines_lf() {
+    let lines: Vec<&str> = "a\r\nb".lines_lf().collect();
+    assert_eq!(lines, ["a\r", "b"]);
+
+    let data = "\nMäry häd ä little lämb\n\r\nLittle lämb\n";
+    let lines: Vec<&str> = data.lines_lf().collect();
+    assert_eq!(lines, ["", "Märy häd ä little lämb", "\r", "Little lämb"]);
+    let lines: Vec<&str> = data.lines_lf().rev().collect();
+    assert_eq!(lines, ["Little lämb", "\r", "Märy häd ä little lämb", ""]);
+
+    // A lone `\r` never ends a line.
+    let lines: Vec<&str> = "a\rb\r".lines_lf().collect();
+    assert_eq!(lines, ["a\rb\r"]);
+
+    for data in ["", "\n", "a\nb", "a\r\nb\r\n", "\r\n\r\n\n"] {
+        assert_eq!(data.lines_lf().count(), data.lines().count());
+        assert_eq!(data.lines_lf().last(), data.split_terminator('\n').last());
+    }
+}
+
+#[test]
+fn test_numbered_lines() {
+    let text = "foo\r\nbar\n\r\nbaz\n";
+    let v: Vec<_> = text.numbered_lines().collect();
+    assert_eq!(v, [(1, "foo"), (2, "bar"), (3, ""), (4, "baz")]);
+    let v: Vec<_> = text.numbered_lines().rev().collect();
+    assert_eq!(v, [(4, "baz"), (3, ""), (2, "bar"), (1, "foo")]);
+
+    // Without a trailing newline the last line still counts, and a lone
+    // trailing newline doesn't add an empty line.
+    let v: Vec<_> = "a\nb".numbered_lines().collect();
+    assert_eq!(v, [(1, "a"), (2, "b")]);
+    let v: Vec<_> = "a\n\n".numbered_lines().rev().collect();
+    assert_eq!(v, [(2, ""), (1, "a")]);
+    assert_eq!("".numbered_lines().next(), None);
+    assert_eq!("".numbered_lines().next_back(), None);
+    assert_eq!("a\r\nb\r\n".numbered_lines().last(), Some((2, "b")));
+
+    // Every interleaving of `next` and `next_back` gives each line its
+    // number from `lines().enumerate()`.
+    let expected: Vec<_> = text.lines().enumerate().map(|(i, line)| (i + 1, line)).collect();
+    for mask in 0u32..1 << 5 {
+        let mut lines = text.numbered_lines();
+        let mut remaining = expected.clone();
+        for step in 0..5 {
+            if mask & (1 << step) == 0 {
+                let line = if remaining.is_empty() { None } else { Some(remaining.remove(0)) };
+                assert_eq!(lines.next(), line);
+            } else {
+                assert_eq!(lines.next_back(), remaining.pop());
+            }
+        }
+    }
+}
+
+#[test]
+fn test_lines_count() {
+    assert_eq!("a\nb\n".lines().count(), 2);
+    assert_eq!("a\nb".lines().count(), 2);
+    assert_eq!("".lines().count(), 0);
+    assert_eq!("\n".lines().count(), 1);
+    assert_eq!("\n\n".lines().count(), 2);
+    assert_eq!("a\r\nb\r\n".lines().count(), 2);
+    assert_eq!("a\r\nb".lines().count(), 2);
+    assert_eq!("\r\n\r\n\n".lines().count(), 3);
+    assert_eq!("a\r".lines().count(), 1);
+
+    // Partially consumed iterators, from either end.
+    for data in ["a\n\nb", "a\n\nb\n", "\nä\r\n\n\r\nb\n\n", "\n"] {
+        for mask in 0u32..1 << 4 {
+            let mut lines = data.lines();
+            for step in 0..4 {
+                assert_eq!(lines.clone().count(), lines.clone().collect::<Vec<_>>().len());
+                if mask & (1 << step) == 0 {
+                    lines.next();
+                } else {
+                    lines.next_back();
+                }
+            }
+        }
+    }
+}
+
+#[test]
+fn test_split_ascii_whitespace_count() {
+    assert_eq!("".split_ascii_whitespace().count(), 0);
+    assert_eq!(" \t\n ".split_ascii_whitespace().count(), 0);
+    assert_eq!("word".split_ascii_whitespace().count(), 1);
+    assert_eq!("  Mary   had\ta\n little  \n\t lamb\x0c".split_ascii_whitespace().count(), 5);
+    // Only ASCII whitespace separates words.
+    assert_eq!("a\u{a0}b\u{3000}c".split_ascii_whitespace().count(), 1);
+
+    // Partially consumed iterators, from either end.
+    for data in ["a b", " a  b ", "\tä\r\n\nb\x0b c", "\n", "lamb"] {
+        for mask in 0u32..1 << 4 {
+            let mut split = data.split_ascii_whitespace();
+            for step in 0..4 {
+                assert_eq!(split.clone().count(), split.clone().collect::<Vec<_>>().len());
+                if mask & (1 << step) == 0 {
+                    split.next();
+                } else {
+                    split.next_back();
+                }
+            }
+        }
+    }
+}
+
+#[test]
+fn test_split_count() {
+    fn t(s: &str, sep: char) {
+        let pieces = s.split(sep).collect::<Vec<_>>().len();
+        assert_eq!(s.split(sep).count(), pieces);
+        assert_eq!(s.rsplit(sep).count(), pieces);
+        let pieces = s.split_terminator(sep).collect::<Vec<_>>().len();
+        assert_eq!(s.split_terminator(sep).count(), pieces);
+        assert_eq!(s.rsplit_terminator(sep).count(), pieces);
+
+        // Partially consumed iterators, from either end.
+        for mask in 0u32..1 << 4 {
+            let mut split = s.split(sep);
+            let mut terminated = s.split_terminator(sep);
+            for step in 0..4 {
+                assert_eq!(split.clone().count(), split.clone().collect::<Vec<_>>().len());
+                assert_eq!(
+                    terminated.clone().count(),
+                    terminated.clone().collect::<Vec<_>>().len()
+                );
+                if mask & (1 << step) == 0 {
+                    split.next();
+                    terminated.next();
+                } else {
+                    split.next_back();
+                    terminated.next_back();
+                }
+            }
+        }
+    }
+
+    assert_eq!("a,b,,c".split(',').count(), 4);
+    assert_eq!("".split(',').count(), 1);
+    assert_eq!("a,b,".split_terminator(',').count(), 2);
+
+    for s in ["a,b,,c", ",a,", ",,", "", "abc", "ä,ö,", ",é"] {
+        t(s, ',');
+    }
+    for s in ["aéb", "éé", "é", "", "abc", "aéééb", "ééaé"] {
+        t(s, 'é');
+    }
+    t("a中b中中c", '中');
+}
+
 #[test]
 fn test_splitator() {
     fn t(s: &str, sep: &str, u: &[&str]) {
@@ -1583,6 +3331,168 @@ fn test_empty_match_indices() {
     assert_eq!(vec, [(0, ""), (1, ""), (3, ""), (6, ""), (7, "")]);
 }
 
+#[test]
+fn test_match_ranges() {
+    let v: Vec<_> = "a1b2".match_ranges(char::is_numeric).collect();
+    assert_eq!(v, [1..2, 3..4]);
+    let v: Vec<_> = "a1b2".match_ranges(char::is_numeric).rev().collect();
+    assert_eq!(v, [3..4, 1..2]);
+
+    // adjacent matches
+    let v: Vec<_> = "12ab".match_ranges(char::is_numeric).collect();
+    assert_eq!(v, [0..1, 1..2]);
+    let v: Vec<_> = "abab".match_ranges("ab").collect();
+    assert_eq!(v, [0..2, 2..4]);
+    let v: Vec<_> = "abab".rmatch_ranges("ab").collect();
+    assert_eq!(v, [2..4, 0..2]);
+
+    // overlapping candidates give non-overlapping ranges, picked from the
+    // side the search starts on
+    let v: Vec<_> = "aaaaa".match_ranges("aa").collect();
+    assert_eq!(v, [0..2, 2..4]);
+    let v: Vec<_> = "aaaaa".rmatch_ranges("aa").collect();
+    assert_eq!(v, [3..5, 1..3]);
+
+    // multibyte matches span all of their bytes
+    let data = "aä中!中";
+    let v: Vec<_> = data.match_ranges('中').collect();
+    assert_eq!(v, [3..6, 7..10]);
+    let v: Vec<_> = data.match_ranges(|c: char| !c.is_ascii()).collect();
+    assert_eq!(v, [1..3, 3..6, 7..10]);
+    let v: Vec<_> = data.match_ranges("ä中").collect();
+    assert_eq!(v, [1..6]);
+    for (range, (i, m)) in data.match_ranges("中").zip(data.match_indices("中")) {
+        assert_eq!(range, i..i + m.len());
+        assert_eq!(&data[range], m);
+    }
+
+    let v: Vec<_> = "aä".match_ranges("").collect();
+    assert_eq!(v, [0..0, 1..1, 3..3]);
+    assert_eq!("".match_ranges('a').next(), None);
+}
+
+#[test]
+fn test_matches_max_items() {
+    let v: Vec<&str> = "a1b2c3".matches(char::is_numeric).max_items(2).collect();
+    assert_eq!(v, ["1", "2"]);
+    let v: Vec<&str> = "a1b2c3".rmatches(char::is_numeric).max_items(2).collect();
+    assert_eq!(v, ["3", "2"]);
+
+    let v: Vec<&str> = "a1b2c3".matches(char::is_numeric).max_items(0).collect();
+    assert!(v.is_empty());
+    let v: Vec<&str> = "a1b2c3".matches(char::is_numeric).max_items(10).collect();
+    assert_eq!(v, ["1", "2", "3"]);
+    let v: Vec<&str> = "abcXXXabcYYYabc".matches("abc").max_items(2).collect();
+    assert_eq!(v, ["abc", "abc"]);
+}
+
+#[test]
+fn test_matches_as_str() {
+    let mut m = "a1b2".rmatches(char::is_numeric);
+    assert_eq!(m.as_str(), "a1b2");
+    assert_eq!(m.next(), Some("2"));
+    assert_eq!(m.as_str(), "a1b");
+    assert_eq!(m.next(), Some("1"));
+    assert_eq!(m.as_str(), "a");
+    assert_eq!(m.next(), None);
+    assert_eq!(m.as_str(), "");
+
+    let mut m = "a1b2".matches(char::is_numeric);
+    assert_eq!(m.next(), Some("1"));
+    assert_eq!(m.as_str(), "b2");
+    assert_eq!(m.next_back(), Some("2"));
+    assert_eq!(m.as_str(), "b");
+    assert_eq!(m.next(), None);
+    assert_eq!(m.as_str(), "");
+
+    let mut m = "xxabcyyabczz".rmatches("abc");
+    assert_eq!(m.next(), Some("abc"));
+    assert_eq!(m.as_str(), "xxabcyy");
+    assert_eq!(m.next(), Some("abc"));
+    assert_eq!(m.as_str(), "xx");
+    assert_eq!(m.next(), None);
+    assert_eq!(m.as_str(), "");
+}
+
+#[test]
+fn test_matches_context() {
+    let v: Vec<_> = "let x = foo + bar;".matches_context('+', 4, 4).collect();
+    assert_eq!(v, [("+", "foo + bar")]);
+
+    // Context is clamped at the start and end of the string.
+    let v: Vec<_> = ",ab,".matches_context(',', 2, 2).collect();
+    assert_eq!(v, [(",", ",ab"), (",", "ab,")]);
+    let v: Vec<_> = "日本,語x".matches_context(',', 100, 100).collect();
+    assert_eq!(v, [(",", "日本,語x")]);
+
+    // Context is counted in chars, so multibyte neighbors are kept whole.
+    let v: Vec<_> = "日本,語x".matches_context(',', 1, 1).collect();
+    assert_eq!(v, [(",", "本,語")]);
+    let v: Vec<_> = "αβγ".matches_context('β', 5, 0).collect();
+    assert_eq!(v, [("β", "αβ")]);
+
+    let v: Vec<_> = "ab,cd".matches_context(',', 0, 0).collect();
+    assert_eq!(v, [(",", ",")]);
+    let v: Vec<_> = "a1b2".matches_context(char::is_numeric, 1, 1).rev().collect();
+    assert_eq!(v, [("2", "b2"), ("1", "a1b")]);
+    assert_eq!("abc".matches_context('x', 1, 1).next(), None);
+}
+
+#[test]
+fn test_matches_and_gaps() {
+    use std::str::MatchOrGap::{Gap, Match};
+
+    let v: Vec<_> = "x = 12+3".matches_and_gaps(char::is_numeric).collect();
+    assert_eq!(v, [Gap("x = "), Match("1"), Match("2"), Gap("+"), Match("3")]);
+
+    // Adjacent matches, and matches at either end, have no gap between them.
+    let v: Vec<_> = "--a--".matches_and_gaps('-').collect();
+    assert_eq!(v, [Match("-"), Match("-"), Gap("a"), Match("-"), Match("-")]);
+    let v: Vec<_> = "aaXaaaY".matches_and_gaps("aa").collect();
+    assert_eq!(v, [Match("aa"), Gap("X"), Match("aa"), Gap("aY")]);
+
+    let v: Vec<_> = "abc".matches_and_gaps('-').collect();
+    assert_eq!(v, [Gap("abc")]);
+    assert_eq!("".matches_and_gaps('-').next(), None);
+
+    // Empty matches are still yielded.
+    let v: Vec<_> = "añ".matches_and_gaps("").collect();
+    assert_eq!(v, [Match(""), Gap("a"), Match(""), Gap("ñ"), Match("")]);
+
+    for (s, pat) in [("", "-"), ("a-b--c", "-"), ("--", "-"), ("αβγ", "β"), ("añb", "")] {
+        let pieces: String = s.matches_and_gaps(pat).map(|piece| piece.as_str()).collect();
+        assert_eq!(pieces, s);
+    }
+
+    let mut it = "a-b".matches_and_gaps('-');
+    assert_eq!(it.by_ref().count(), 3);
+    assert_eq!(it.next(), None);
+}
+
+#[test]
+fn test_matches_count() {
+    assert_eq!("aaaa".matches("aa").count(), 2);
+    assert_eq!("aaaa".rmatches("aa").count(), 2);
+    assert_eq!("aaa".matches("aa").count(), 1);
+    assert_eq!("aaa".rmatches("aa").count(), 1);
+    assert_eq!("abc".matches("x").count(), 0);
+    assert_eq!("abc".rmatches('x').count(), 0);
+    assert_eq!("".matches("a").count(), 0);
+    assert_eq!("abc".matches("").count(), 4);
+    assert_eq!("a1b2c3".matches(char::is_numeric).count(), 3);
+
+    let data = "Märy häd ä little lämb";
+    for pat in ["ä", "l", "", "lämb", "x"] {
+        assert_eq!(data.matches(pat).count(), data.matches(pat).fold(0, |n, _| n + 1));
+        assert_eq!(data.rmatches(pat).count(), data.rmatches(pat).fold(0, |n, _| n + 1));
+    }
+
+    // only the remaining matches are counted
+    let mut m = "a1b2c3".matches(char::is_numeric);
+    m.next();
+    assert_eq!(m.count(), 2);
+}
+
 #[test]
 fn test_bool_from_str() {
     assert_eq!("true".parse().ok(), Some(true));
@@ -1696,6 +3606,62 @@ fn test_utf16_code_units() {
     assert_eq!("é\u{1F4A9}".encode_utf16().collect::<Vec<u16>>(), [0xE9, 0xD83D, 0xDCA9])
 }
 
+#[test]
+fn test_line_ending_pattern() {
+    use std::str::pattern::LineEnding;
+
+    let v: Vec<_> = "a\r\nb\n".match_indices(LineEnding).collect();
+    assert_eq!(v, [(1, "\r\n"), (4, "\n")]);
+    let v: Vec<_> = "a\r\nb\n".rmatch_indices(LineEnding).collect();
+    assert_eq!(v, [(4, "\n"), (1, "\r\n")]);
+
+    // `\r\n` is one unit, and a lone `\r` is not a line ending
+    let v: Vec<&str> = "a\r\r\nb\rc\n".split(LineEnding).collect();
+    assert_eq!(v, ["a\r", "b\rc", ""]);
+    let v: Vec<&str> = "a\r\r\nb\rc\n".split(LineEnding).rev().collect();
+    assert_eq!(v, ["", "b\rc", "a\r"]);
+    let v: Vec<&str> = "a\r\nb\n".split_inclusive(LineEnding).collect();
+    assert_eq!(v, ["a\r\n", "b\n"]);
+
+    assert_eq!("a\r\n".strip_suffix(LineEnding), Some("a"));
+    assert_eq!("\r\na".strip_prefix(LineEnding), Some("a"));
+    assert_eq!("a\r".strip_suffix(LineEnding), None);
+    assert_eq!("äb\r\n".find(LineEnding), Some(3));
+}
+
+#[test]
+fn test_utf16_write_to() {
+    const UNITS: [u16; 3] = [0xE9, 0xD83D, 0xDCA9];
+    let s = "é\u{1F4A9}";
+
+    // exact fit
+    let mut buf = [0; 3];
+    assert_eq!(s.encode_utf16().write_to(&mut buf), Ok(3));
+    assert_eq!(buf, UNITS);
+
+    // oversized
+    let mut buf = [0xFFFF; 5];
+    assert_eq!(s.encode_utf16().write_to(&mut buf), Ok(3));
+    assert_eq!(buf, [0xE9, 0xD83D, 0xDCA9, 0xFFFF, 0xFFFF]);
+
+    // too small: never write half of a surrogate pair
+    let mut buf = [0xFFFF; 2];
+    assert_eq!(s.encode_utf16().write_to(&mut buf), Err(3));
+    assert_eq!(buf, [0xE9, 0xFFFF]);
+    assert_eq!(s.encode_utf16().write_to(&mut []), Err(3));
+
+    // a pending trailing surrogate is written first
+    let mut iter = s.encode_utf16();
+    assert_eq!(iter.next(), Some(0xE9));
+    assert_eq!(iter.next(), Some(0xD83D));
+    let mut buf = [0; 1];
+    assert_eq!(iter.clone().write_to(&mut buf), Ok(1));
+    assert_eq!(buf, [0xDCA9]);
+    assert_eq!(iter.write_to(&mut []), Err(1));
+
+    assert_eq!("".encode_utf16().write_to(&mut []), Ok(0));
+}
+
 #[test]
 fn starts_with_in_unicode() {
     assert!(!"├── Cargo.toml".starts_with("# "));
@@ -1794,6 +3760,32 @@ fn to_uppercase() {
     assert_eq!("aéǅßﬁᾀ".to_uppercase(), "AÉǄSSFIἈΙ");
 }
 
+#[test]
+fn eq_ignore_case() {
+    assert!("".eq_ignore_case(""));
+    assert!("Ferris".eq_ignore_case("fERRIS"));
+    assert!(!"Ferris".eq_ignore_case("Ferri"));
+    assert!(!"abc".eq_ignore_case("abd"));
+
+    // One-to-many foldings.
+    assert!("STRASSE".eq_ignore_case("straße"));
+    assert!("straẞe".eq_ignore_case("STRASSE"));
+    assert!("ẞ".eq_ignore_case("ß"));
+    assert!("ﬀ".eq_ignore_case("FF"));
+    assert!(!"ß".eq_ignore_case("s"));
+
+    // Non-ASCII characters that fold into ASCII.
+    assert!("\u{212A}elvin".eq_ignore_case("kelvin"));
+    assert!("ſ".eq_ignore_case("S"));
+
+    assert!("ΣΊΣΥΦΟΣ".eq_ignore_case("σίσυφος"));
+    assert!("ς".eq_ignore_case("Σ"));
+    assert!("İ".eq_ignore_case("i\u{307}"));
+    assert!(!"ı".eq_ignore_case("I"));
+    assert!(!"ı".eq_ignore_case("i"));
+    assert!("aéǅßﬁᾀ".eq_ignore_case(&"aéǅßﬁᾀ".to_uppercase()));
+}
+
 #[test]
 fn test_into_string() {
     // The only way to acquire a Box<str> in the first place is through a String, so just
@@ -1827,6 +3819,31 @@ fn test_repeat() {
     assert_eq!("α".repeat(3), "ααα");
 }
 
+#[test]
+fn test_repeat_into() {
+    let mut buf = [0xFF; 8];
+    assert_eq!("α".repeat_into(3, &mut buf), Ok(6));
+    assert_eq!(from_utf8(&buf[..6]), Ok("ααα"));
+    assert_eq!(buf[6..], [0xFF; 2]);
+
+    // exact fit
+    assert_eq!("abcd".repeat_into(2, &mut buf), Ok(8));
+    assert_eq!(&buf, b"abcdabcd");
+
+    // too small: nothing is written, not even a partial copy
+    let mut buf = [0xFF; 5];
+    assert_eq!("ab".repeat_into(3, &mut buf), Err(6));
+    assert_eq!(buf, [0xFF; 5]);
+    assert_eq!("ab".repeat_into(usize::MAX, &mut buf), Err(usize::MAX));
+    assert_eq!(buf, [0xFF; 5]);
+
+    // nothing to write
+    assert_eq!("abc".repeat_into(0, &mut []), Ok(0));
+    assert_eq!("".repeat_into(usize::MAX, &mut []), Ok(0));
+    assert_eq!("abc".repeat_into(0, &mut buf), Ok(0));
+    assert_eq!(buf, [0xFF; 5]);
+}
+
 mod pattern {
     use std::str::pattern::SearchStep::{self, Done, Match, Reject};
     use std::str::pattern::{Pattern, ReverseSearcher, Searcher};
@@ -1976,6 +3993,27 @@ fn cmp_search_to_vec<'a>(
         "* \t",
         [Reject(0, 1), Reject(1, 2), Reject(2, 3),]
     );
+    make_test!(
+        line_ending_searcher_haystack,
+        std::str::pattern::LineEnding,
+        "a\r\nb\n\r\r\nc\r",
+        [
+            Reject(0, 1),
+            Match(1, 3),
+            Reject(3, 4),
+            Match(4, 5),
+            Reject(5, 6),
+            Match(6, 8),
+            Reject(8, 10),
+        ]
+    );
+    make_test!(
+        line_ending_searcher_only_endings,
+        std::str::pattern::LineEnding,
+        "\n\r\n\n",
+        [Match(0, 1), Match(1, 3), Match(3, 4),]
+    );
+    make_test!(line_ending_searcher_empty_haystack, std::str::pattern::LineEnding, "", []);
 
     // See #85462
     #[test]
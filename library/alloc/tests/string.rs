@@ -301,6 +301,22 @@ fn test_split_off_unicode() {
     assert_eq!(nihon.capacity(), orig_capacity);
 }
 
+#[test]
+fn test_into_split() {
+    let cases = [("", ','), (",", ','), ("a,b,,c,", ','), ("abc", ','), ("日本語", '本')];
+    for (s, pat) in cases {
+        let owned: Vec<String> = String::from(s).into_split(pat).collect();
+        let borrowed: Vec<&str> = s.split(pat).collect();
+        assert_eq!(owned, borrowed);
+    }
+
+    let mut it = String::from("a b").into_split(' ');
+    assert_eq!(it.next().as_deref(), Some("a"));
+    assert_eq!(it.next().as_deref(), Some("b"));
+    assert_eq!(it.next(), None);
+    assert_eq!(it.next(), None);
+}
+
 #[test]
 fn test_str_truncate() {
     let mut s = String::from("12345");
@@ -639,6 +655,41 @@ fn end_bound(&self) -> Bound<&usize> {
     assert_eq!(Ok(""), str::from_utf8(s.as_bytes()));
 }
 
+#[test]
+fn test_from_iterator_chars() {
+    let s = "ศไทย中华Việt Nam 🦀";
+
+    // `Chars` takes a fast path; `Map<Chars, _>` goes through the generic one.
+    let fast: String = s.chars().collect();
+    let slow: String = s.chars().map(|c| c).collect();
+    assert_eq!(fast, s);
+    assert_eq!(fast, slow);
+
+    let mut chars = s.chars();
+    chars.next();
+    chars.next_back();
+    chars.next_back();
+    let rest = chars.as_str();
+    let a: String = chars.collect();
+    assert_eq!(a, rest);
+    assert_eq!(a, "ไทย中华Việt Nam");
+
+    let mut chars = s.chars();
+    chars.by_ref().for_each(drop);
+    let b: String = chars.collect();
+    assert_eq!(b, "");
+
+    let mut c = String::from("🦀");
+    c.extend(s.chars().skip(4));
+    assert_eq!(c, "🦀中华Việt Nam 🦀");
+
+    let long = s.repeat(1000);
+    let fast: String = long.chars().collect();
+    let slow: String = long.chars().map(|c| c).collect();
+    assert_eq!(fast, slow);
+    assert_eq!(fast, long);
+}
+
 #[test]
 fn test_extend_ref() {
     let mut a = "foo".to_string();
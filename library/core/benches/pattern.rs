@@ -40,3 +40,15 @@ fn ends_with_str(b: &mut Bencher) {
         }
     })
 }
+
+#[bench]
+fn split_csv_ascii_char(b: &mut Bencher) {
+    let text = black_box("field,another field,42,,".repeat(1 << 16));
+    b.iter(|| text.split(',').map(str::len).sum::<usize>())
+}
+
+#[bench]
+fn split_csv_ascii_closure(b: &mut Bencher) {
+    let text = black_box("field,another field,42,,".repeat(1 << 16));
+    b.iter(|| text.split(|c: char| c == ',').map(str::len).sum::<usize>())
+}
@@ -3,6 +3,7 @@
 #[cfg(not(bootstrap))]
 use crate::error::Error;
 use crate::fmt;
+use crate::iter::FusedIterator;
 
 use super::from_u32_unchecked;
 
@@ -108,6 +109,54 @@ fn size_hint(&self) -> (usize, Option<usize>) {
     }
 }
 
+/// An iterator that decodes UTF-16 encoded code points from an iterator of
+/// `u16`s, replacing unpaired surrogates with [`REPLACEMENT_CHARACTER`].
+///
+/// This `struct` is created by the [`from_utf16_lossy_chars`] function.
+/// See its documentation for more.
+///
+/// [`REPLACEMENT_CHARACTER`]: char::REPLACEMENT_CHARACTER
+/// [`from_utf16_lossy_chars`]: super::from_utf16_lossy_chars
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+#[unstable(feature = "from_utf16_lossy_chars", issue = "none")]
+#[derive(Clone, Debug)]
+pub struct FromUtf16LossyChars<I>
+where
+    I: Iterator<Item = u16>,
+{
+    inner: DecodeUtf16<I>,
+}
+
+/// Creates an iterator over the UTF-16 encoded code points in `iter`,
+/// replacing unpaired surrogates with `U+FFFD`. See
+/// [`char::from_utf16_lossy_chars`](super::from_utf16_lossy_chars).
+#[inline]
+pub(super) fn from_utf16_lossy_chars<I: IntoIterator<Item = u16>>(
+    iter: I,
+) -> FromUtf16LossyChars<I::IntoIter> {
+    FromUtf16LossyChars { inner: decode_utf16(iter) }
+}
+
+#[unstable(feature = "from_utf16_lossy_chars", issue = "none")]
+impl<I: Iterator<Item = u16>> Iterator for FromUtf16LossyChars<I> {
+    type Item = char;
+
+    #[inline]
+    fn next(&mut self) -> Option<char> {
+        self.inner.next().map(|r| r.unwrap_or(char::REPLACEMENT_CHARACTER))
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.inner.size_hint()
+    }
+}
+
+// `DecodeUtf16` only returns `None` once both its buffer and `I` are empty, so
+// it stays exhausted as long as `I` does.
+#[unstable(feature = "from_utf16_lossy_chars", issue = "none")]
+impl<I: FusedIterator<Item = u16>> FusedIterator for FromUtf16LossyChars<I> {}
+
 impl DecodeUtf16Error {
     /// Returns the unpaired surrogate which caused this error.
     #[must_use]
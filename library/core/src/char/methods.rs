@@ -436,6 +436,17 @@ pub(crate) fn escape_debug_ext(self, args: EscapeDebugExtArgs) -> EscapeDebug {
         EscapeDebug(EscapeDefault { state: init_state })
     }
 
+    /// Escapes `self` like `escape_debug` if it is a control character, and
+    /// otherwise yields it unchanged. Used by `str::escape_control`.
+    #[inline]
+    pub(crate) fn escape_control(self) -> EscapeDebug {
+        if self.is_control() {
+            self.escape_debug_ext(EscapeDebugExtArgs::ESCAPE_ALL)
+        } else {
+            EscapeDebug(EscapeDefault { state: EscapeDefaultState::Char(self) })
+        }
+    }
+
     /// Returns an iterator that yields the literal escape code of a character
     /// as `char`s.
     ///
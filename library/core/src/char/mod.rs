@@ -29,6 +29,8 @@
 pub use self::convert::CharTryFromError;
 #[stable(feature = "char_from_str", since = "1.20.0")]
 pub use self::convert::ParseCharError;
+#[unstable(feature = "from_utf16_lossy_chars", issue = "none")]
+pub use self::decode::FromUtf16LossyChars;
 #[stable(feature = "decode_utf16", since = "1.9.0")]
 pub use self::decode::{DecodeUtf16, DecodeUtf16Error};
 
@@ -109,6 +111,35 @@ pub fn decode_utf16<I: IntoIterator<Item = u16>>(iter: I) -> DecodeUtf16<I::Into
     self::decode::decode_utf16(iter)
 }
 
+/// Creates an iterator over the UTF-16 encoded code points in `iter`,
+/// replacing unpaired surrogates with [`REPLACEMENT_CHARACTER`].
+///
+/// This is the lossy counterpart of [`char::decode_utf16`], yielding `char`s
+/// directly instead of `Result`s, and so is the inverse of
+/// [`str::encode_utf16`] for any valid input.
+///
+/// # Examples
+///
+/// ```
+/// #![feature(from_utf16_lossy_chars)]
+/// use std::char::from_utf16_lossy_chars;
+///
+/// // 𝄞mus<invalid>ic<invalid>
+/// let v = [0xD834, 0xDD1E, 0x006d, 0x0075, 0x0073, 0xDD1E, 0x0069, 0x0063, 0xD834];
+///
+/// assert_eq!(from_utf16_lossy_chars(v).collect::<String>(), "𝄞mus\u{FFFD}ic\u{FFFD}");
+///
+/// let s = "a𝄞b";
+/// assert_eq!(from_utf16_lossy_chars(s.encode_utf16()).collect::<String>(), s);
+/// ```
+#[unstable(feature = "from_utf16_lossy_chars", issue = "none")]
+#[inline]
+pub fn from_utf16_lossy_chars<I: IntoIterator<Item = u16>>(
+    iter: I,
+) -> FromUtf16LossyChars<I::IntoIter> {
+    self::decode::from_utf16_lossy_chars(iter)
+}
+
 /// Converts a `u32` to a `char`. Use [`char::from_u32`] instead.
 #[stable(feature = "rust1", since = "1.0.0")]
 #[rustc_const_unstable(feature = "const_char_convert", issue = "89259")]
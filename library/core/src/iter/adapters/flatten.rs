@@ -17,6 +17,13 @@ impl<I: Iterator, U: IntoIterator, F: FnMut(I::Item) -> U> FlatMap<I, U, F> {
     pub(in crate::iter) fn new(iter: I, f: F) -> FlatMap<I, U, F> {
         FlatMap { inner: FlattenCompat::new(iter.map(f)) }
     }
+
+    /// Splits the adapter into the partially used front iterator, the rest
+    /// of the mapped base iterator, and the partially used back iterator.
+    pub(crate) fn into_parts(self) -> (Option<U::IntoIter>, Fuse<Map<I, F>>, Option<U::IntoIter>) {
+        let FlattenCompat { iter, frontiter, backiter } = self.inner;
+        (frontiter, iter, backiter)
+    }
 }
 
 #[stable(feature = "rust1", since = "1.0.0")]
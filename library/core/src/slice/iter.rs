@@ -11,6 +11,7 @@
 use crate::marker::{PhantomData, Send, Sized, Sync};
 use crate::mem;
 use crate::num::NonZeroUsize;
+use crate::ops::Range;
 use crate::ptr::NonNull;
 
 use super::{from_raw_parts, from_raw_parts_mut};
@@ -372,6 +373,13 @@ pub(super) trait SplitIter: DoubleEndedIterator {
     /// Marks the underlying iterator as complete, extracting the remaining
     /// portion of the slice.
     fn finish(&mut self) -> Option<Self::Item>;
+
+    /// Splits off the portion of the slice that `finish` would return after
+    /// `n` more calls to `next`, leaving just the `n` subslices before it.
+    ///
+    /// Returns `None` without changing the iterator if fewer than `n + 1`
+    /// subslices remain.
+    fn split_off_rest(&mut self, n: usize) -> Option<Self::Item>;
 }
 
 /// An iterator over subslices separated by elements that match a predicate
@@ -513,11 +521,131 @@ fn finish(&mut self) -> Option<&'a [T]> {
             Some(self.v)
         }
     }
+
+    #[inline]
+    fn split_off_rest(&mut self, n: usize) -> Option<&'a [T]> {
+        if n == 0 {
+            return self.finish();
+        }
+        if self.finished {
+            return None;
+        }
+
+        // The rest starts after the `n`th match.
+        let mut matches = 0;
+        let idx = self.v.iter().position(|x| {
+            matches += (self.pred)(x) as usize;
+            matches == n
+        })?;
+        let rest = &self.v[idx + 1..];
+        self.v = &self.v[..idx];
+        Some(rest)
+    }
 }
 
 #[stable(feature = "fused", since = "1.26.0")]
 impl<T, P> FusedIterator for Split<'_, T, P> where P: FnMut(&T) -> bool {}
 
+/// An iterator over the index ranges of subslices separated by elements that
+/// match a predicate function.
+///
+/// This struct is created by the [`split_ranges`] method on [slices].
+///
+/// # Example
+///
+/// ```
+/// #![feature(slice_split_ranges)]
+/// let slice = [10, 40, 33, 20];
+/// let mut iter = slice.split_ranges(|num| num % 3 == 0);
+/// ```
+///
+/// [`split_ranges`]: slice::split_ranges
+/// [slices]: slice
+#[unstable(feature = "slice_split_ranges", issue = "none")]
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct SplitRanges<'a, T: 'a, P>
+where
+    P: FnMut(&T) -> bool,
+{
+    inner: Split<'a, T, P>,
+    /// The index of `inner.v` in the original slice
+    offset: usize,
+}
+
+impl<'a, T: 'a, P: FnMut(&T) -> bool> SplitRanges<'a, T, P> {
+    #[inline]
+    pub(super) fn new(slice: &'a [T], pred: P) -> Self {
+        Self { inner: Split::new(slice, pred), offset: 0 }
+    }
+}
+
+#[unstable(feature = "slice_split_ranges", issue = "none")]
+impl<T: fmt::Debug, P> fmt::Debug for SplitRanges<'_, T, P>
+where
+    P: FnMut(&T) -> bool,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("SplitRanges")
+            .field("inner", &self.inner)
+            .field("offset", &self.offset)
+            .finish()
+    }
+}
+
+// FIXME(#26925) Remove in favor of `#[derive(Clone)]`
+#[unstable(feature = "slice_split_ranges", issue = "none")]
+impl<T, P> Clone for SplitRanges<'_, T, P>
+where
+    P: Clone + FnMut(&T) -> bool,
+{
+    fn clone(&self) -> Self {
+        SplitRanges { inner: self.inner.clone(), offset: self.offset }
+    }
+}
+
+#[unstable(feature = "slice_split_ranges", issue = "none")]
+impl<'a, T, P> Iterator for SplitRanges<'a, T, P>
+where
+    P: FnMut(&T) -> bool,
+{
+    type Item = Range<usize>;
+
+    #[inline]
+    fn next(&mut self) -> Option<Range<usize>> {
+        // Each piece starts at the front of the unsplit remainder.
+        let piece = self.inner.next()?;
+        let start = self.offset;
+        // Only step past the separator if there is one: after the last piece
+        // this could overflow for a slice of `usize::MAX` ZSTs.
+        if !self.inner.finished {
+            self.offset += piece.len() + 1;
+        }
+        Some(start..start + piece.len())
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.inner.size_hint()
+    }
+}
+
+#[unstable(feature = "slice_split_ranges", issue = "none")]
+impl<'a, T, P> DoubleEndedIterator for SplitRanges<'a, T, P>
+where
+    P: FnMut(&T) -> bool,
+{
+    #[inline]
+    fn next_back(&mut self) -> Option<Range<usize>> {
+        // Each piece ends at the back of the unsplit remainder.
+        let end = self.offset + self.inner.v.len();
+        let piece = self.inner.next_back()?;
+        Some(end - piece.len()..end)
+    }
+}
+
+#[unstable(feature = "slice_split_ranges", issue = "none")]
+impl<T, P> FusedIterator for SplitRanges<'_, T, P> where P: FnMut(&T) -> bool {}
+
 /// An iterator over subslices separated by elements that match a predicate
 /// function. Unlike `Split`, it contains the matched part as a terminator
 /// of the subslice.
@@ -695,6 +823,31 @@ fn finish(&mut self) -> Option<&'a mut [T]> {
             Some(mem::replace(&mut self.v, &mut []))
         }
     }
+
+    #[inline]
+    fn split_off_rest(&mut self, n: usize) -> Option<&'a mut [T]> {
+        if n == 0 {
+            return self.finish();
+        }
+        if self.finished {
+            return None;
+        }
+
+        // The rest starts after the `n`th match.
+        let idx = {
+            // work around borrowck limitations
+            let pred = &mut self.pred;
+            let mut matches = 0;
+            self.v.iter().position(|x| {
+                matches += (*pred)(x) as usize;
+                matches == n
+            })?
+        };
+        let tmp = mem::replace(&mut self.v, &mut []);
+        let (head, tail) = tmp.split_at_mut(idx);
+        self.v = head;
+        Some(&mut tail[1..])
+    }
 }
 
 #[stable(feature = "rust1", since = "1.0.0")]
@@ -893,6 +1046,261 @@ fn next_back(&mut self) -> Option<&'a mut [T]> {
 #[stable(feature = "split_inclusive", since = "1.51.0")]
 impl<T, P> FusedIterator for SplitInclusiveMut<'_, T, P> where P: FnMut(&T) -> bool {}
 
+/// An iterator over subslices separated by elements that match a predicate
+/// function. Unlike `Split`, each matched element is contained in the
+/// following subslice as its first element.
+///
+/// This struct is created by the [`split_inclusive_before`] method on [slices].
+///
+/// # Example
+///
+/// ```
+/// #![feature(slice_split_inclusive_before)]
+///
+/// let slice = [10, 40, 33, 20];
+/// let mut iter = slice.split_inclusive_before(|num| num % 3 == 0);
+/// ```
+///
+/// [`split_inclusive_before`]: slice::split_inclusive_before
+/// [slices]: slice
+#[unstable(feature = "slice_split_inclusive_before", issue = "none")]
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct SplitInclusiveBefore<'a, T: 'a, P>
+where
+    P: FnMut(&T) -> bool,
+{
+    v: &'a [T],
+    pred: P,
+    finished: bool,
+}
+
+impl<'a, T: 'a, P: FnMut(&T) -> bool> SplitInclusiveBefore<'a, T, P> {
+    #[inline]
+    pub(super) fn new(slice: &'a [T], pred: P) -> Self {
+        let finished = slice.is_empty();
+        Self { v: slice, pred, finished }
+    }
+}
+
+#[unstable(feature = "slice_split_inclusive_before", issue = "none")]
+impl<T: fmt::Debug, P> fmt::Debug for SplitInclusiveBefore<'_, T, P>
+where
+    P: FnMut(&T) -> bool,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("SplitInclusiveBefore")
+            .field("v", &self.v)
+            .field("finished", &self.finished)
+            .finish()
+    }
+}
+
+// FIXME(#26925) Remove in favor of `#[derive(Clone)]`
+#[unstable(feature = "slice_split_inclusive_before", issue = "none")]
+impl<T, P> Clone for SplitInclusiveBefore<'_, T, P>
+where
+    P: Clone + FnMut(&T) -> bool,
+{
+    fn clone(&self) -> Self {
+        SplitInclusiveBefore { v: self.v, pred: self.pred.clone(), finished: self.finished }
+    }
+}
+
+#[unstable(feature = "slice_split_inclusive_before", issue = "none")]
+impl<'a, T, P> Iterator for SplitInclusiveBefore<'a, T, P>
+where
+    P: FnMut(&T) -> bool,
+{
+    type Item = &'a [T];
+
+    #[inline]
+    fn next(&mut self) -> Option<&'a [T]> {
+        if self.finished {
+            return None;
+        }
+
+        // The first element of self.v either was found to match by the last
+        // iteration or is the start of the slice, so it always belongs to
+        // this subslice and we start searching one index to the right.
+        let remainder = if self.v.is_empty() { &[] } else { &self.v[1..] };
+        let idx = remainder
+            .iter()
+            .position(|x| (self.pred)(x))
+            .map(|idx| idx + 1)
+            .unwrap_or(self.v.len());
+        if idx == self.v.len() {
+            self.finished = true;
+        }
+        let ret = Some(&self.v[..idx]);
+        self.v = &self.v[idx..];
+        ret
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        if self.finished {
+            (0, Some(0))
+        } else {
+            // If the predicate doesn't match anything, we yield one slice.
+            // If it matches every element, we yield `len()` one-element slices,
+            // or a single empty slice.
+            (1, Some(cmp::max(1, self.v.len())))
+        }
+    }
+}
+
+#[unstable(feature = "slice_split_inclusive_before", issue = "none")]
+impl<'a, T, P> DoubleEndedIterator for SplitInclusiveBefore<'a, T, P>
+where
+    P: FnMut(&T) -> bool,
+{
+    #[inline]
+    fn next_back(&mut self) -> Option<&'a [T]> {
+        if self.finished {
+            return None;
+        }
+
+        let idx = self.v.iter().rposition(|x| (self.pred)(x)).unwrap_or(0);
+        if idx == 0 {
+            self.finished = true;
+        }
+        let ret = Some(&self.v[idx..]);
+        self.v = &self.v[..idx];
+        ret
+    }
+}
+
+#[unstable(feature = "slice_split_inclusive_before", issue = "none")]
+impl<T, P> FusedIterator for SplitInclusiveBefore<'_, T, P> where P: FnMut(&T) -> bool {}
+
+/// An iterator over the mutable subslices of the vector which are separated
+/// by elements that match `pred`. Unlike `SplitMut`, each matched element is
+/// contained in the following subslice as its first element.
+///
+/// This struct is created by the [`split_inclusive_before_mut`] method on
+/// [slices].
+///
+/// # Example
+///
+/// ```
+/// #![feature(slice_split_inclusive_before)]
+///
+/// let mut v = [10, 40, 30, 20, 60, 50];
+/// let iter = v.split_inclusive_before_mut(|num| *num % 3 == 0);
+/// ```
+///
+/// [`split_inclusive_before_mut`]: slice::split_inclusive_before_mut
+/// [slices]: slice
+#[unstable(feature = "slice_split_inclusive_before", issue = "none")]
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct SplitInclusiveBeforeMut<'a, T: 'a, P>
+where
+    P: FnMut(&T) -> bool,
+{
+    v: &'a mut [T],
+    pred: P,
+    finished: bool,
+}
+
+impl<'a, T: 'a, P: FnMut(&T) -> bool> SplitInclusiveBeforeMut<'a, T, P> {
+    #[inline]
+    pub(super) fn new(slice: &'a mut [T], pred: P) -> Self {
+        let finished = slice.is_empty();
+        Self { v: slice, pred, finished }
+    }
+}
+
+#[unstable(feature = "slice_split_inclusive_before", issue = "none")]
+impl<T: fmt::Debug, P> fmt::Debug for SplitInclusiveBeforeMut<'_, T, P>
+where
+    P: FnMut(&T) -> bool,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("SplitInclusiveBeforeMut")
+            .field("v", &self.v)
+            .field("finished", &self.finished)
+            .finish()
+    }
+}
+
+#[unstable(feature = "slice_split_inclusive_before", issue = "none")]
+impl<'a, T, P> Iterator for SplitInclusiveBeforeMut<'a, T, P>
+where
+    P: FnMut(&T) -> bool,
+{
+    type Item = &'a mut [T];
+
+    #[inline]
+    fn next(&mut self) -> Option<&'a mut [T]> {
+        if self.finished {
+            return None;
+        }
+
+        let idx_opt = if self.v.is_empty() {
+            None
+        } else {
+            // work around borrowck limitations
+            let pred = &mut self.pred;
+
+            // The first element of self.v either was found to match by the
+            // last iteration or is the start of the slice, so we start
+            // searching a new match one index to the right.
+            let remainder = &self.v[1..];
+            remainder.iter().position(|x| (*pred)(x))
+        };
+        let idx = idx_opt.map(|idx| idx + 1).unwrap_or(self.v.len());
+        if idx == self.v.len() {
+            self.finished = true;
+        }
+        let tmp = mem::replace(&mut self.v, &mut []);
+        let (head, tail) = tmp.split_at_mut(idx);
+        self.v = tail;
+        Some(head)
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        if self.finished {
+            (0, Some(0))
+        } else {
+            // If the predicate doesn't match anything, we yield one slice.
+            // If it matches every element, we yield `len()` one-element slices,
+            // or a single empty slice.
+            (1, Some(cmp::max(1, self.v.len())))
+        }
+    }
+}
+
+#[unstable(feature = "slice_split_inclusive_before", issue = "none")]
+impl<'a, T, P> DoubleEndedIterator for SplitInclusiveBeforeMut<'a, T, P>
+where
+    P: FnMut(&T) -> bool,
+{
+    #[inline]
+    fn next_back(&mut self) -> Option<&'a mut [T]> {
+        if self.finished {
+            return None;
+        }
+
+        let idx_opt = {
+            // work around borrowck limitations
+            let pred = &mut self.pred;
+            self.v.iter().rposition(|x| (*pred)(x))
+        };
+        let idx = idx_opt.unwrap_or(0);
+        if idx == 0 {
+            self.finished = true;
+        }
+        let tmp = mem::replace(&mut self.v, &mut []);
+        let (head, tail) = tmp.split_at_mut(idx);
+        self.v = head;
+        Some(tail)
+    }
+}
+
+#[unstable(feature = "slice_split_inclusive_before", issue = "none")]
+impl<T, P> FusedIterator for SplitInclusiveBeforeMut<'_, T, P> where P: FnMut(&T) -> bool {}
+
 /// An iterator over subslices separated by elements that match a predicate
 /// function, starting from the end of the slice.
 ///
@@ -985,6 +1393,26 @@ impl<'a, T, P> SplitIter for RSplit<'a, T, P>
     fn finish(&mut self) -> Option<&'a [T]> {
         self.inner.finish()
     }
+
+    #[inline]
+    fn split_off_rest(&mut self, n: usize) -> Option<&'a [T]> {
+        if n == 0 {
+            return self.finish();
+        }
+        if self.inner.finished {
+            return None;
+        }
+
+        // The rest ends before the `n`th match from the back.
+        let mut matches = 0;
+        let idx = self.inner.v.iter().rposition(|x| {
+            matches += (self.inner.pred)(x) as usize;
+            matches == n
+        })?;
+        let rest = &self.inner.v[..idx];
+        self.inner.v = &self.inner.v[idx + 1..];
+        Some(rest)
+    }
 }
 
 #[stable(feature = "slice_rsplit", since = "1.27.0")]
@@ -1042,6 +1470,31 @@ impl<'a, T, P> SplitIter for RSplitMut<'a, T, P>
     fn finish(&mut self) -> Option<&'a mut [T]> {
         self.inner.finish()
     }
+
+    #[inline]
+    fn split_off_rest(&mut self, n: usize) -> Option<&'a mut [T]> {
+        if n == 0 {
+            return self.finish();
+        }
+        if self.inner.finished {
+            return None;
+        }
+
+        // The rest ends before the `n`th match from the back.
+        let idx = {
+            // work around borrowck limitations
+            let pred = &mut self.inner.pred;
+            let mut matches = 0;
+            self.inner.v.iter().rposition(|x| {
+                matches += (*pred)(x) as usize;
+                matches == n
+            })?
+        };
+        let tmp = mem::replace(&mut self.inner.v, &mut []);
+        let (head, tail) = tmp.split_at_mut(idx);
+        self.inner.v = &mut tail[1..];
+        Some(head)
+    }
 }
 
 #[stable(feature = "slice_rsplit", since = "1.27.0")]
@@ -1083,6 +1536,9 @@ impl<T, P> FusedIterator for RSplitMut<'_, T, P> where P: FnMut(&T) -> bool {}
 struct GenericSplitN<I> {
     iter: I,
     count: usize,
+    // Whether at most `count` subslices are known to be left in `iter`, so
+    // that `next_back` can take them straight from the back.
+    within_count: bool,
 }
 
 impl<T, I: SplitIter<Item = T>> Iterator for GenericSplitN<I> {
@@ -1113,6 +1569,28 @@ fn size_hint(&self) -> (usize, Option<usize>) {
     }
 }
 
+impl<T, I: SplitIter<Item = T>> DoubleEndedIterator for GenericSplitN<I> {
+    #[inline]
+    fn next_back(&mut self) -> Option<T> {
+        if self.count == 0 {
+            return None;
+        }
+        self.count -= 1;
+        if self.within_count {
+            return self.iter.next_back();
+        }
+        // If more subslices remain than the budget allows, the last one is
+        // whatever is left after the splits still to be made from the front.
+        // Cutting it off (or finding there's nothing to cut) leaves at most
+        // `count` subslices, so this forward scan only happens once.
+        self.within_count = true;
+        match self.iter.split_off_rest(self.count) {
+            None => self.iter.next_back(),
+            rest => rest,
+        }
+    }
+}
+
 /// An iterator over subslices separated by elements that match a predicate
 /// function, limited to a given number of splits.
 ///
@@ -1139,7 +1617,7 @@ pub struct SplitN<'a, T: 'a, P>
 impl<'a, T: 'a, P: FnMut(&T) -> bool> SplitN<'a, T, P> {
     #[inline]
     pub(super) fn new(s: Split<'a, T, P>, n: usize) -> Self {
-        Self { inner: GenericSplitN { iter: s, count: n } }
+        Self { inner: GenericSplitN { iter: s, count: n, within_count: false } }
     }
 }
 
@@ -1180,7 +1658,7 @@ pub struct RSplitN<'a, T: 'a, P>
 impl<'a, T: 'a, P: FnMut(&T) -> bool> RSplitN<'a, T, P> {
     #[inline]
     pub(super) fn new(s: RSplit<'a, T, P>, n: usize) -> Self {
-        Self { inner: GenericSplitN { iter: s, count: n } }
+        Self { inner: GenericSplitN { iter: s, count: n, within_count: false } }
     }
 }
 
@@ -1220,7 +1698,7 @@ pub struct SplitNMut<'a, T: 'a, P>
 impl<'a, T: 'a, P: FnMut(&T) -> bool> SplitNMut<'a, T, P> {
     #[inline]
     pub(super) fn new(s: SplitMut<'a, T, P>, n: usize) -> Self {
-        Self { inner: GenericSplitN { iter: s, count: n } }
+        Self { inner: GenericSplitN { iter: s, count: n, within_count: false } }
     }
 }
 
@@ -1261,7 +1739,7 @@ pub struct RSplitNMut<'a, T: 'a, P>
 impl<'a, T: 'a, P: FnMut(&T) -> bool> RSplitNMut<'a, T, P> {
     #[inline]
     pub(super) fn new(s: RSplitMut<'a, T, P>, n: usize) -> Self {
-        Self { inner: GenericSplitN { iter: s, count: n } }
+        Self { inner: GenericSplitN { iter: s, count: n, within_count: false } }
     }
 }
 
@@ -3386,3 +3864,200 @@ fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         f.debug_struct("GroupByMut").field("slice", &self.slice).finish()
     }
 }
+
+/// An iterator over slice in (non-overlapping) runs of elements with equal keys.
+///
+/// This struct is created by the [`chunk_by_key`] method on [slices].
+///
+/// [`chunk_by_key`]: slice::chunk_by_key
+/// [slices]: slice
+#[unstable(feature = "slice_chunk_by_key", issue = "none")]
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct ChunkByKey<'a, T: 'a, F> {
+    slice: &'a [T],
+    key: F,
+}
+
+#[unstable(feature = "slice_chunk_by_key", issue = "none")]
+impl<'a, T: 'a, F> ChunkByKey<'a, T, F> {
+    pub(super) fn new(slice: &'a [T], key: F) -> Self {
+        ChunkByKey { slice, key }
+    }
+}
+
+#[unstable(feature = "slice_chunk_by_key", issue = "none")]
+impl<'a, T: 'a, K, F> Iterator for ChunkByKey<'a, T, F>
+where
+    K: PartialEq,
+    F: FnMut(&T) -> K,
+{
+    type Item = &'a [T];
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        let mut iter = self.slice.iter();
+        let mut prev = (self.key)(iter.next()?);
+        let mut len = 1;
+        for x in iter {
+            let key = (self.key)(x);
+            if key != prev {
+                break;
+            }
+            prev = key;
+            len += 1;
+        }
+        let (head, tail) = self.slice.split_at(len);
+        self.slice = tail;
+        Some(head)
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        if self.slice.is_empty() { (0, Some(0)) } else { (1, Some(self.slice.len())) }
+    }
+
+    #[inline]
+    fn last(mut self) -> Option<Self::Item> {
+        self.next_back()
+    }
+}
+
+#[unstable(feature = "slice_chunk_by_key", issue = "none")]
+impl<'a, T: 'a, K, F> DoubleEndedIterator for ChunkByKey<'a, T, F>
+where
+    K: PartialEq,
+    F: FnMut(&T) -> K,
+{
+    #[inline]
+    fn next_back(&mut self) -> Option<Self::Item> {
+        let mut iter = self.slice.iter();
+        let mut prev = (self.key)(iter.next_back()?);
+        let mut len = 1;
+        for x in iter.rev() {
+            let key = (self.key)(x);
+            if key != prev {
+                break;
+            }
+            prev = key;
+            len += 1;
+        }
+        let (head, tail) = self.slice.split_at(self.slice.len() - len);
+        self.slice = head;
+        Some(tail)
+    }
+}
+
+#[unstable(feature = "slice_chunk_by_key", issue = "none")]
+impl<'a, T: 'a, K, F> FusedIterator for ChunkByKey<'a, T, F>
+where
+    K: PartialEq,
+    F: FnMut(&T) -> K,
+{
+}
+
+#[unstable(feature = "slice_chunk_by_key", issue = "none")]
+impl<'a, T: 'a + fmt::Debug, F> fmt::Debug for ChunkByKey<'a, T, F> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ChunkByKey").field("slice", &self.slice).finish()
+    }
+}
+
+/// An iterator over slice in (non-overlapping) mutable runs of elements
+/// with equal keys.
+///
+/// This struct is created by the [`chunk_by_key_mut`] method on [slices].
+///
+/// [`chunk_by_key_mut`]: slice::chunk_by_key_mut
+/// [slices]: slice
+#[unstable(feature = "slice_chunk_by_key", issue = "none")]
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct ChunkByKeyMut<'a, T: 'a, F> {
+    slice: &'a mut [T],
+    key: F,
+}
+
+#[unstable(feature = "slice_chunk_by_key", issue = "none")]
+impl<'a, T: 'a, F> ChunkByKeyMut<'a, T, F> {
+    pub(super) fn new(slice: &'a mut [T], key: F) -> Self {
+        ChunkByKeyMut { slice, key }
+    }
+}
+
+#[unstable(feature = "slice_chunk_by_key", issue = "none")]
+impl<'a, T: 'a, K, F> Iterator for ChunkByKeyMut<'a, T, F>
+where
+    K: PartialEq,
+    F: FnMut(&T) -> K,
+{
+    type Item = &'a mut [T];
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        let mut iter = self.slice.iter();
+        let mut prev = (self.key)(iter.next()?);
+        let mut len = 1;
+        for x in iter {
+            let key = (self.key)(x);
+            if key != prev {
+                break;
+            }
+            prev = key;
+            len += 1;
+        }
+        let slice = mem::take(&mut self.slice);
+        let (head, tail) = slice.split_at_mut(len);
+        self.slice = tail;
+        Some(head)
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        if self.slice.is_empty() { (0, Some(0)) } else { (1, Some(self.slice.len())) }
+    }
+
+    #[inline]
+    fn last(mut self) -> Option<Self::Item> {
+        self.next_back()
+    }
+}
+
+#[unstable(feature = "slice_chunk_by_key", issue = "none")]
+impl<'a, T: 'a, K, F> DoubleEndedIterator for ChunkByKeyMut<'a, T, F>
+where
+    K: PartialEq,
+    F: FnMut(&T) -> K,
+{
+    #[inline]
+    fn next_back(&mut self) -> Option<Self::Item> {
+        let mut iter = self.slice.iter();
+        let mut prev = (self.key)(iter.next_back()?);
+        let mut len = 1;
+        for x in iter.rev() {
+            let key = (self.key)(x);
+            if key != prev {
+                break;
+            }
+            prev = key;
+            len += 1;
+        }
+        let slice = mem::take(&mut self.slice);
+        let (head, tail) = slice.split_at_mut(slice.len() - len);
+        self.slice = head;
+        Some(tail)
+    }
+}
+
+#[unstable(feature = "slice_chunk_by_key", issue = "none")]
+impl<'a, T: 'a, K, F> FusedIterator for ChunkByKeyMut<'a, T, F>
+where
+    K: PartialEq,
+    F: FnMut(&T) -> K,
+{
+}
+
+#[unstable(feature = "slice_chunk_by_key", issue = "none")]
+impl<'a, T: 'a + fmt::Debug, F> fmt::Debug for ChunkByKeyMut<'a, T, F> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ChunkByKeyMut").field("slice", &self.slice).finish()
+    }
+}
@@ -417,6 +417,17 @@ fn size_hint(&self) -> (usize, Option<usize>) {
             }
         }
 
+        #[unstable(feature = "slice_splitn_double_ended", issue = "none")]
+        impl<'a, $elem, P> DoubleEndedIterator for $name<'a, $elem, P>
+        where
+            P: FnMut(&T) -> bool,
+        {
+            #[inline]
+            fn next_back(&mut self) -> Option<$iter_of> {
+                self.inner.next_back()
+            }
+        }
+
         #[stable(feature = "fused", since = "1.26.0")]
         impl<'a, $elem, P> FusedIterator for $name<'a, $elem, P> where P: FnMut(&T) -> bool {}
     };
@@ -62,9 +62,18 @@
 #[unstable(feature = "slice_group_by", issue = "80552")]
 pub use iter::{GroupBy, GroupByMut};
 
+#[unstable(feature = "slice_chunk_by_key", issue = "none")]
+pub use iter::{ChunkByKey, ChunkByKeyMut};
+
 #[stable(feature = "split_inclusive", since = "1.51.0")]
 pub use iter::{SplitInclusive, SplitInclusiveMut};
 
+#[unstable(feature = "slice_split_inclusive_before", issue = "none")]
+pub use iter::{SplitInclusiveBefore, SplitInclusiveBeforeMut};
+
+#[unstable(feature = "slice_split_ranges", issue = "none")]
+pub use iter::SplitRanges;
+
 #[stable(feature = "rust1", since = "1.0.0")]
 pub use raw::{from_raw_parts, from_raw_parts_mut};
 
@@ -1510,6 +1519,66 @@ pub fn group_by_mut<F>(&mut self, pred: F) -> GroupByMut<'_, T, F>
         GroupByMut::new(self, pred)
     }
 
+    /// Returns an iterator over the slice producing non-overlapping runs
+    /// of elements whose keys, as computed by `key`, are equal.
+    ///
+    /// Keys are compared between adjacent elements, so each run is maximal:
+    /// neighbouring runs always have different keys. `key` is called once on
+    /// each element of a run, and once on the element ending it.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(slice_chunk_by_key)]
+    ///
+    /// let slice = &[("a", 1), ("a", 2), ("b", 3)];
+    ///
+    /// let mut iter = slice.chunk_by_key(|&(name, _)| name);
+    ///
+    /// assert_eq!(iter.next(), Some(&[("a", 1), ("a", 2)][..]));
+    /// assert_eq!(iter.next(), Some(&[("b", 3)][..]));
+    /// assert_eq!(iter.next(), None);
+    /// ```
+    #[unstable(feature = "slice_chunk_by_key", issue = "none")]
+    #[inline]
+    pub fn chunk_by_key<K, F>(&self, key: F) -> ChunkByKey<'_, T, F>
+    where
+        K: PartialEq,
+        F: FnMut(&T) -> K,
+    {
+        ChunkByKey::new(self, key)
+    }
+
+    /// Returns an iterator over the slice producing non-overlapping mutable
+    /// runs of elements whose keys, as computed by `key`, are equal.
+    ///
+    /// Keys are compared between adjacent elements, so each run is maximal:
+    /// neighbouring runs always have different keys. `key` is called once on
+    /// each element of a run, and once on the element ending it.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(slice_chunk_by_key)]
+    ///
+    /// let slice = &mut [1, 3, 2, 4, 4, 5];
+    ///
+    /// for run in slice.chunk_by_key_mut(|n| n % 2) {
+    ///     run.reverse();
+    /// }
+    ///
+    /// assert_eq!(slice, &[3, 1, 4, 4, 2, 5]);
+    /// ```
+    #[unstable(feature = "slice_chunk_by_key", issue = "none")]
+    #[inline]
+    pub fn chunk_by_key_mut<K, F>(&mut self, key: F) -> ChunkByKeyMut<'_, T, F>
+    where
+        K: PartialEq,
+        F: FnMut(&T) -> K,
+    {
+        ChunkByKeyMut::new(self, key)
+    }
+
     /// Divides one slice into two at an index.
     ///
     /// The first will contain all indices from `[0, mid)` (excluding
@@ -1695,6 +1764,96 @@ pub unsafe fn split_at_mut_unchecked(&mut self, mid: usize) -> (&mut [T], &mut [
         }
     }
 
+    /// Divides one slice into two at an index, returning `None` if the slice
+    /// is too short.
+    ///
+    /// If `mid <= len` returns a pair of slices where the first will contain all
+    /// indices from `[0, mid)` (excluding the index `mid` itself) and the
+    /// second will contain all indices from `[mid, len)` (excluding the index
+    /// `len` itself).
+    ///
+    /// Otherwise, if `mid > len`, returns `None`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(split_at_checked)]
+    ///
+    /// let v = [1, -2, 3, -4, 5, -6];
+    ///
+    /// {
+    ///    let (left, right) = v.split_at_checked(0).unwrap();
+    ///    assert_eq!(left, []);
+    ///    assert_eq!(right, [1, -2, 3, -4, 5, -6]);
+    /// }
+    ///
+    /// {
+    ///     let (left, right) = v.split_at_checked(2).unwrap();
+    ///     assert_eq!(left, [1, -2]);
+    ///     assert_eq!(right, [3, -4, 5, -6]);
+    /// }
+    ///
+    /// {
+    ///     let (left, right) = v.split_at_checked(6).unwrap();
+    ///     assert_eq!(left, [1, -2, 3, -4, 5, -6]);
+    ///     assert_eq!(right, []);
+    /// }
+    ///
+    /// assert_eq!(None, v.split_at_checked(7));
+    /// ```
+    #[unstable(feature = "split_at_checked", issue = "none")]
+    #[inline]
+    #[must_use]
+    pub fn split_at_checked(&self, mid: usize) -> Option<(&[T], &[T])> {
+        if mid <= self.len() {
+            // SAFETY: `[ptr; mid]` and `[mid; len]` are inside `self`, which
+            // fulfills the requirements of `split_at_unchecked`.
+            Some(unsafe { self.split_at_unchecked(mid) })
+        } else {
+            None
+        }
+    }
+
+    /// Divides one mutable slice into two at an index, returning `None` if the
+    /// slice is too short.
+    ///
+    /// If `mid <= len` returns a pair of slices where the first will contain all
+    /// indices from `[0, mid)` (excluding the index `mid` itself) and the
+    /// second will contain all indices from `[mid, len)` (excluding the index
+    /// `len` itself).
+    ///
+    /// Otherwise, if `mid > len`, returns `None`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(split_at_checked)]
+    ///
+    /// let mut v = [1, 0, 3, 0, 5, 6];
+    ///
+    /// if let Some((left, right)) = v.split_at_mut_checked(2) {
+    ///     assert_eq!(left, [1, 0]);
+    ///     assert_eq!(right, [3, 0, 5, 6]);
+    ///     left[1] = 2;
+    ///     right[1] = 4;
+    /// }
+    /// assert_eq!(v, [1, 2, 3, 4, 5, 6]);
+    ///
+    /// assert_eq!(None, v.split_at_mut_checked(7));
+    /// ```
+    #[unstable(feature = "split_at_checked", issue = "none")]
+    #[inline]
+    #[must_use]
+    pub fn split_at_mut_checked(&mut self, mid: usize) -> Option<(&mut [T], &mut [T])> {
+        if mid <= self.len() {
+            // SAFETY: `[ptr; mid]` and `[mid; len]` are inside `self`, which
+            // fulfills the requirements of `split_at_mut_unchecked`.
+            Some(unsafe { self.split_at_mut_unchecked(mid) })
+        } else {
+            None
+        }
+    }
+
     /// Divides one slice into an array and a remainder slice at an index.
     ///
     /// The array will contain all indices from `[0, N)` (excluding
@@ -1902,6 +2061,43 @@ pub fn split<F>(&self, pred: F) -> Split<'_, T, F>
         Split::new(self, pred)
     }
 
+    /// Returns an iterator over the index ranges of subslices separated by
+    /// elements that match `pred`. The matched element is not contained in
+    /// the ranges.
+    ///
+    /// This yields the same subslices as [`split`], but as ranges of indices
+    /// into `self`. They can be used to correlate each subslice with its
+    /// position, or with other buffers, without keeping `self` borrowed.
+    ///
+    /// [`split`]: slice::split
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(slice_split_ranges)]
+    /// let slice = [1, 0, 2, 0, 3];
+    /// let ranges: Vec<_> = slice.split_ranges(|&x| x == 0).collect();
+    /// assert_eq!(ranges, [0..1, 2..3, 4..5]);
+    /// ```
+    ///
+    /// Leading, trailing and adjacent matches produce empty ranges, just as
+    /// [`split`] produces empty subslices:
+    ///
+    /// ```
+    /// #![feature(slice_split_ranges)]
+    /// let slice = [0, 1, 0, 0];
+    /// let ranges: Vec<_> = slice.split_ranges(|&x| x == 0).collect();
+    /// assert_eq!(ranges, [0..0, 1..2, 3..3, 4..4]);
+    /// ```
+    #[unstable(feature = "slice_split_ranges", issue = "none")]
+    #[inline]
+    pub fn split_ranges<F>(&self, pred: F) -> SplitRanges<'_, T, F>
+    where
+        F: FnMut(&T) -> bool,
+    {
+        SplitRanges::new(self, pred)
+    }
+
     /// Returns an iterator over mutable subslices separated by elements that
     /// match `pred`. The matched element is not contained in the subslices.
     ///
@@ -1984,6 +2180,71 @@ pub fn split_inclusive_mut<F>(&mut self, pred: F) -> SplitInclusiveMut<'_, T, F>
         SplitInclusiveMut::new(self, pred)
     }
 
+    /// Returns an iterator over subslices separated by elements that match
+    /// `pred`. The matched element is contained in the following subslice
+    /// as its first element.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(slice_split_inclusive_before)]
+    ///
+    /// let slice = [1, 0, 2, 0, 3];
+    /// let mut iter = slice.split_inclusive_before(|num| *num == 0);
+    ///
+    /// assert_eq!(iter.next().unwrap(), &[1]);
+    /// assert_eq!(iter.next().unwrap(), &[0, 2]);
+    /// assert_eq!(iter.next().unwrap(), &[0, 3]);
+    /// assert!(iter.next().is_none());
+    /// ```
+    ///
+    /// If the first element of the slice is matched, it starts the first
+    /// subslice; no empty subslice is returned before it.
+    ///
+    /// ```
+    /// #![feature(slice_split_inclusive_before)]
+    ///
+    /// let slice = [3, 10, 40, 33];
+    /// let mut iter = slice.split_inclusive_before(|num| num % 3 == 0);
+    ///
+    /// assert_eq!(iter.next().unwrap(), &[3, 10, 40]);
+    /// assert_eq!(iter.next().unwrap(), &[33]);
+    /// assert!(iter.next().is_none());
+    /// ```
+    #[unstable(feature = "slice_split_inclusive_before", issue = "none")]
+    #[inline]
+    pub fn split_inclusive_before<F>(&self, pred: F) -> SplitInclusiveBefore<'_, T, F>
+    where
+        F: FnMut(&T) -> bool,
+    {
+        SplitInclusiveBefore::new(self, pred)
+    }
+
+    /// Returns an iterator over mutable subslices separated by elements that
+    /// match `pred`. The matched element is contained in the following
+    /// subslice as its first element.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(slice_split_inclusive_before)]
+    ///
+    /// let mut v = [10, 40, 30, 20, 60, 50];
+    ///
+    /// for group in v.split_inclusive_before_mut(|num| *num % 3 == 0) {
+    ///     group[0] = 1;
+    /// }
+    /// assert_eq!(v, [1, 40, 1, 20, 1, 50]);
+    /// ```
+    #[unstable(feature = "slice_split_inclusive_before", issue = "none")]
+    #[inline]
+    pub fn split_inclusive_before_mut<F>(&mut self, pred: F) -> SplitInclusiveBeforeMut<'_, T, F>
+    where
+        F: FnMut(&T) -> bool,
+    {
+        SplitInclusiveBeforeMut::new(self, pred)
+    }
+
     /// Returns an iterator over subslices separated by elements that match
     /// `pred`, starting at the end of the slice and working backwards.
     /// The matched element is not contained in the subslices.
@@ -2156,6 +2417,214 @@ pub fn rsplitn_mut<F>(&mut self, n: usize, pred: F) -> RSplitNMut<'_, T, F>
         RSplitNMut::new(self.rsplit_mut(pred), n)
     }
 
+    /// Splits the slice on the first element that matches the specified
+    /// predicate.
+    ///
+    /// If any matching elements are present in the slice, returns the prefix
+    /// before the match and suffix after. The matching element itself is not
+    /// included. If no elements match, returns `None`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(slice_split_once)]
+    /// let s = [1, 2, 0, 3, 0, 4];
+    /// assert_eq!(s.split_once(|&x| x == 0), Some((&[1, 2][..], &[3, 0, 4][..])));
+    /// assert_eq!(s.split_once(|&x| x == 5), None);
+    /// ```
+    #[unstable(feature = "slice_split_once", issue = "none")]
+    #[inline]
+    pub fn split_once<F>(&self, pred: F) -> Option<(&[T], &[T])>
+    where
+        F: FnMut(&T) -> bool,
+    {
+        let index = self.iter().position(pred)?;
+        Some((&self[..index], &self[index + 1..]))
+    }
+
+    /// Splits the mutable slice on the first element that matches the
+    /// specified predicate.
+    ///
+    /// If any matching elements are present in the slice, returns the prefix
+    /// before the match and suffix after. The matching element itself is not
+    /// included. If no elements match, returns `None`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(slice_split_once)]
+    /// let mut s = [1, 2, 0, 3, 0, 4];
+    /// if let Some((left, right)) = s.split_once_mut(|&x| x == 0) {
+    ///     left[0] = 5;
+    ///     right[0] = 6;
+    /// }
+    /// assert_eq!(s, [5, 2, 0, 6, 0, 4]);
+    /// ```
+    #[unstable(feature = "slice_split_once", issue = "none")]
+    #[inline]
+    pub fn split_once_mut<F>(&mut self, pred: F) -> Option<(&mut [T], &mut [T])>
+    where
+        F: FnMut(&T) -> bool,
+    {
+        let index = self.iter().position(pred)?;
+        let (left, right) = self.split_at_mut(index);
+        Some((left, &mut right[1..]))
+    }
+
+    /// Splits the slice on the last element that matches the specified
+    /// predicate.
+    ///
+    /// If any matching elements are present in the slice, returns the prefix
+    /// before the match and suffix after. The matching element itself is not
+    /// included. If no elements match, returns `None`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(slice_split_once)]
+    /// let s = [1, 2, 0, 3, 0, 4];
+    /// assert_eq!(s.rsplit_once(|&x| x == 0), Some((&[1, 2, 0, 3][..], &[4][..])));
+    /// assert_eq!(s.rsplit_once(|&x| x == 5), None);
+    /// ```
+    #[unstable(feature = "slice_split_once", issue = "none")]
+    #[inline]
+    pub fn rsplit_once<F>(&self, pred: F) -> Option<(&[T], &[T])>
+    where
+        F: FnMut(&T) -> bool,
+    {
+        let index = self.iter().rposition(pred)?;
+        Some((&self[..index], &self[index + 1..]))
+    }
+
+    /// Splits the mutable slice on the last element that matches the
+    /// specified predicate.
+    ///
+    /// If any matching elements are present in the slice, returns the prefix
+    /// before the match and suffix after. The matching element itself is not
+    /// included. If no elements match, returns `None`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(slice_split_once)]
+    /// let mut s = [1, 2, 0, 3, 0, 4];
+    /// if let Some((left, right)) = s.rsplit_once_mut(|&x| x == 0) {
+    ///     left[0] = 5;
+    ///     right[0] = 6;
+    /// }
+    /// assert_eq!(s, [5, 2, 0, 3, 0, 6]);
+    /// ```
+    #[unstable(feature = "slice_split_once", issue = "none")]
+    #[inline]
+    pub fn rsplit_once_mut<F>(&mut self, pred: F) -> Option<(&mut [T], &mut [T])>
+    where
+        F: FnMut(&T) -> bool,
+    {
+        let index = self.iter().rposition(pred)?;
+        let (left, right) = self.split_at_mut(index);
+        Some((left, &mut right[1..]))
+    }
+
+    /// Splits the slice after the longest prefix whose elements all match
+    /// `pred`.
+    ///
+    /// Returns the matching prefix and the rest of the slice. The rest starts
+    /// with the first element that doesn't match, if there is one.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(slice_split_off_prefix)]
+    /// let s = [1, 1, 0, 2];
+    /// assert_eq!(s.split_off_prefix(|&x| x != 0), (&[1, 1][..], &[0, 2][..]));
+    /// assert_eq!(s.split_off_prefix(|&x| x == 0), (&[][..], &s[..]));
+    /// ```
+    #[unstable(feature = "slice_split_off_prefix", issue = "none")]
+    #[inline]
+    pub fn split_off_prefix<F>(&self, mut pred: F) -> (&[T], &[T])
+    where
+        F: FnMut(&T) -> bool,
+    {
+        let index = self.iter().position(|x| !pred(x)).unwrap_or(self.len());
+        self.split_at(index)
+    }
+
+    /// Splits the mutable slice after the longest prefix whose elements all
+    /// match `pred`.
+    ///
+    /// Returns the matching prefix and the rest of the slice. The rest starts
+    /// with the first element that doesn't match, if there is one.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(slice_split_off_prefix)]
+    /// let mut s = [1, 1, 0, 2];
+    /// let (prefix, rest) = s.split_off_prefix_mut(|&x| x != 0);
+    /// prefix.fill(3);
+    /// rest[0] = 4;
+    /// assert_eq!(s, [3, 3, 4, 2]);
+    /// ```
+    #[unstable(feature = "slice_split_off_prefix", issue = "none")]
+    #[inline]
+    pub fn split_off_prefix_mut<F>(&mut self, mut pred: F) -> (&mut [T], &mut [T])
+    where
+        F: FnMut(&T) -> bool,
+    {
+        let index = self.iter().position(|x| !pred(x)).unwrap_or(self.len());
+        self.split_at_mut(index)
+    }
+
+    /// Splits the slice before the longest suffix whose elements all match
+    /// `pred`.
+    ///
+    /// Returns the rest of the slice and the matching suffix. The rest ends
+    /// with the last element that doesn't match, if there is one.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(slice_split_off_prefix)]
+    /// let s = [1, 0, 2, 2];
+    /// assert_eq!(s.split_off_suffix(|&x| x != 0), (&[1, 0][..], &[2, 2][..]));
+    /// assert_eq!(s.split_off_suffix(|&x| x == 0), (&s[..], &[][..]));
+    /// ```
+    #[unstable(feature = "slice_split_off_prefix", issue = "none")]
+    #[inline]
+    pub fn split_off_suffix<F>(&self, mut pred: F) -> (&[T], &[T])
+    where
+        F: FnMut(&T) -> bool,
+    {
+        let index = self.iter().rposition(|x| !pred(x)).map_or(0, |i| i + 1);
+        self.split_at(index)
+    }
+
+    /// Splits the mutable slice before the longest suffix whose elements all
+    /// match `pred`.
+    ///
+    /// Returns the rest of the slice and the matching suffix. The rest ends
+    /// with the last element that doesn't match, if there is one.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(slice_split_off_prefix)]
+    /// let mut s = [1, 0, 2, 2];
+    /// let (rest, suffix) = s.split_off_suffix_mut(|&x| x != 0);
+    /// rest[0] = 4;
+    /// suffix.fill(3);
+    /// assert_eq!(s, [4, 0, 3, 3]);
+    /// ```
+    #[unstable(feature = "slice_split_off_prefix", issue = "none")]
+    #[inline]
+    pub fn split_off_suffix_mut<F>(&mut self, mut pred: F) -> (&mut [T], &mut [T])
+    where
+        F: FnMut(&T) -> bool,
+    {
+        let index = self.iter().rposition(|x| !pred(x)).map_or(0, |i| i + 1);
+        self.split_at_mut(index)
+    }
+
     /// Returns `true` if the slice contains an element with the given value.
     ///
     /// This operation is *O*(*n*).
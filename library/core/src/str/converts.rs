@@ -95,6 +95,34 @@ pub const fn from_utf8(v: &[u8]) -> Result<&str, Utf8Error> {
     }
 }
 
+/// Returns the length of the longest prefix of `v` that is valid UTF-8.
+///
+/// This is the same as [`Utf8Error::valid_up_to`] for invalid input, and
+/// `v.len()` when all of `v` is valid. It is useful when decoding a stream
+/// in chunks, where a multibyte sequence may be split across two chunks.
+///
+/// # Examples
+///
+/// ```
+/// #![feature(str_valid_prefix_len)]
+/// use std::str;
+///
+/// // "ab" followed by the first three bytes of "💖"
+/// assert_eq!(str::valid_prefix_len(b"ab\xF0\x9F\x92"), 2);
+///
+/// assert_eq!(str::valid_prefix_len("ab💖".as_bytes()), 6);
+/// assert_eq!(str::valid_prefix_len(b"a\xFFb"), 1);
+/// ```
+#[must_use]
+#[unstable(feature = "str_valid_prefix_len", issue = "none")]
+#[rustc_const_unstable(feature = "str_valid_prefix_len", issue = "none")]
+pub const fn valid_prefix_len(v: &[u8]) -> usize {
+    match run_utf8_validation(v) {
+        Ok(_) => v.len(),
+        Err(err) => err.valid_up_to,
+    }
+}
+
 /// Converts a mutable slice of bytes to a mutable string slice.
 ///
 /// # Examples
@@ -1,22 +1,24 @@
 //! Iterators for `str` methods.
 
 use crate::char;
+use crate::cmp;
 use crate::fmt::{self, Write};
 use crate::iter::{Chain, FlatMap, Flatten};
 use crate::iter::{Copied, Filter, FusedIterator, Map, TrustedLen};
 use crate::iter::{TrustedRandomAccess, TrustedRandomAccessNoCoerce};
-use crate::ops::Try;
+use crate::mem;
+use crate::ops::{NeverShortCircuit, Range, Try};
 use crate::option;
 use crate::slice::{self, Split as SliceSplit};
 
 use super::from_utf8_unchecked;
 use super::pattern::Pattern;
 use super::pattern::{DoubleEndedSearcher, ReverseSearcher, Searcher};
-use super::validations::{next_code_point, next_code_point_reverse};
+use super::validations::{next_code_point, next_code_point_reverse, utf8_is_cont_byte};
 use super::LinesAnyMap;
 use super::{BytesIsNotEmpty, UnsafeBytesToStr};
-use super::{CharEscapeDebugContinue, CharEscapeDefault, CharEscapeUnicode};
-use super::{IsAsciiWhitespace, IsNotEmpty, IsWhitespace};
+use super::{CharEscapeControl, CharEscapeDebugContinue, CharEscapeDefault, CharEscapeUnicode};
+use super::{IsAsciiWhitespace, IsNotEmpty, IsWhitespace, TrimWhitespace};
 
 /// An iterator over the [`char`]s of a string slice.
 ///
@@ -83,6 +85,22 @@ fn next_back(&mut self) -> Option<char> {
         // the resulting `ch` is a valid Unicode Scalar Value.
         unsafe { next_code_point_reverse(&mut self.iter).map(|ch| char::from_u32_unchecked(ch)) }
     }
+
+    #[inline]
+    fn nth_back(&mut self, n: usize) -> Option<char> {
+        // Skip the last `n` chars without decoding them: each one is a run of
+        // continuation bytes preceded by a single leading byte.
+        for _ in 0..n {
+            loop {
+                match self.iter.next_back() {
+                    None => return None,
+                    Some(&byte) if utf8_is_cont_byte(byte) => {}
+                    Some(_) => break,
+                }
+            }
+        }
+        self.next_back()
+    }
 }
 
 #[stable(feature = "fused", since = "1.26.0")]
@@ -113,6 +131,58 @@ pub fn as_str(&self) -> &'a str {
         // SAFETY: `Chars` is only made from a str, which guarantees the iter is valid UTF-8.
         unsafe { from_utf8_unchecked(self.iter.as_slice()) }
     }
+
+    /// Returns the next char without advancing the iterator.
+    ///
+    /// This decodes a single char from a copy of the iterator, so it takes
+    /// constant time and doesn't need a [`Peekable`] wrapper.
+    ///
+    /// [`Peekable`]: crate::iter::Peekable
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(chars_peek)]
+    /// let mut chars = "añ".chars();
+    ///
+    /// assert_eq!(chars.peek(), Some('a'));
+    /// assert_eq!(chars.next(), Some('a'));
+    /// assert_eq!(chars.peek(), Some('ñ'));
+    /// assert_eq!(chars.peek(), Some('ñ'));
+    /// chars.next();
+    /// assert_eq!(chars.peek(), None);
+    /// ```
+    #[unstable(feature = "chars_peek", issue = "none")]
+    #[must_use]
+    #[inline]
+    pub fn peek(&self) -> Option<char> {
+        self.clone().next()
+    }
+
+    /// Returns the last char without advancing the iterator from the back.
+    ///
+    /// Like [`peek`], this takes constant time.
+    ///
+    /// [`peek`]: Chars::peek
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(chars_peek)]
+    /// let mut chars = "añ".chars();
+    ///
+    /// assert_eq!(chars.peek_back(), Some('ñ'));
+    /// assert_eq!(chars.next_back(), Some('ñ'));
+    /// assert_eq!(chars.peek_back(), Some('a'));
+    /// chars.next_back();
+    /// assert_eq!(chars.peek_back(), None);
+    /// ```
+    #[unstable(feature = "chars_peek", issue = "none")]
+    #[must_use]
+    #[inline]
+    pub fn peek_back(&self) -> Option<char> {
+        self.clone().next_back()
+    }
 }
 
 /// An iterator over the [`char`]s of a string slice, and their positions.
@@ -155,6 +225,7 @@ fn count(self) -> usize {
 
     #[inline]
     fn size_hint(&self) -> (usize, Option<usize>) {
+        // Each item is one `char` of `self.iter`, so the bounds are the same.
         self.iter.size_hint()
     }
 
@@ -163,6 +234,28 @@ fn last(mut self) -> Option<(usize, char)> {
         // No need to go through the entire string.
         self.next_back()
     }
+
+    #[inline]
+    fn nth(&mut self, n: usize) -> Option<(usize, char)> {
+        // Find the start of the `n`th char without decoding the ones before
+        // it, by counting leading (non-continuation) bytes.
+        let bytes = self.iter.iter.as_slice();
+        let mut lead_bytes = 0;
+        let start = bytes
+            .iter()
+            .position(|&byte| {
+                if !utf8_is_cont_byte(byte) {
+                    lead_bytes += 1;
+                }
+                lead_bytes > n
+            })
+            .unwrap_or(bytes.len());
+        // `start` is either the index of a leading byte or the end of the
+        // slice, so the remaining bytes are still valid UTF-8.
+        self.iter.iter = bytes[start..].iter();
+        self.front_offset += start;
+        self.next()
+    }
 }
 
 #[stable(feature = "rust1", since = "1.0.0")]
@@ -174,6 +267,14 @@ fn next_back(&mut self) -> Option<(usize, char)> {
             (index, ch)
         })
     }
+
+    #[inline]
+    fn nth_back(&mut self, n: usize) -> Option<(usize, char)> {
+        self.iter.nth_back(n).map(|ch| {
+            let index = self.front_offset + self.iter.iter.len();
+            (index, ch)
+        })
+    }
 }
 
 #[stable(feature = "fused", since = "1.26.0")]
@@ -215,8 +316,127 @@ pub fn as_str(&self) -> &'a str {
     pub fn offset(&self) -> usize {
         self.front_offset
     }
+
+    /// Returns the byte position just past the last character not yet
+    /// yielded from the back, or [`offset`] if there are no more characters.
+    ///
+    /// The characters remaining in the iterator always occupy the byte range
+    /// `offset()..offset_back()` of the underlying string.
+    ///
+    /// [`offset`]: CharIndices::offset
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(char_indices_offset)]
+    /// let mut chars = "a楽".char_indices();
+    ///
+    /// assert_eq!(chars.offset_back(), 4);
+    /// assert_eq!(chars.next_back(), Some((1, '楽')));
+    ///
+    /// assert_eq!(chars.offset_back(), 1);
+    /// assert_eq!(chars.next_back(), Some((0, 'a')));
+    ///
+    /// assert_eq!(chars.offset_back(), 0);
+    /// assert_eq!(chars.next_back(), None);
+    /// ```
+    #[inline]
+    #[must_use]
+    #[unstable(feature = "char_indices_offset", issue = "83871")]
+    pub fn offset_back(&self) -> usize {
+        self.front_offset + self.iter.iter.len()
+    }
+
+    /// Returns an upper bound on the number of characters remaining in the
+    /// iterator, without scanning them.
+    ///
+    /// This is the upper bound of [`size_hint`]: the number of bytes left,
+    /// since every character takes at least one byte. The lower bound assumes
+    /// every character takes four bytes. Neither bound is tightened for pure
+    /// ASCII input, because telling that the remaining bytes are all ASCII
+    /// would need a scan of the string; use [`count`] if the exact number is
+    /// needed.
+    ///
+    /// [`size_hint`]: Iterator::size_hint
+    /// [`count`]: Iterator::count
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(char_indices_char_count_upper_bound)]
+    /// let mut chars = "a楽".char_indices();
+    ///
+    /// assert_eq!(chars.char_count_upper_bound(), 4);
+    /// assert_eq!(chars.size_hint(), (1, Some(4)));
+    ///
+    /// chars.next();
+    /// assert_eq!(chars.char_count_upper_bound(), 3);
+    /// assert_eq!(chars.size_hint(), (1, Some(3)));
+    /// ```
+    #[inline]
+    #[must_use]
+    #[unstable(feature = "char_indices_char_count_upper_bound", issue = "none")]
+    pub fn char_count_upper_bound(&self) -> usize {
+        self.iter.iter.len()
+    }
+}
+
+/// An iterator over the char boundaries of a string slice, as byte positions.
+///
+/// This struct is created by the [`char_boundary_indices`] method on [`str`].
+/// See its documentation for more.
+///
+/// [`char_boundary_indices`]: str::char_boundary_indices
+#[derive(Clone, Debug)]
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+#[unstable(feature = "str_char_boundary_indices", issue = "none")]
+pub struct CharBoundaryIndices<'a> {
+    pub(super) inner: CharIndices<'a>,
+    /// The length of the string, until it has been yielded as the last
+    /// boundary.
+    pub(super) end: Option<usize>,
+}
+
+#[unstable(feature = "str_char_boundary_indices", issue = "none")]
+impl<'a> Iterator for CharBoundaryIndices<'a> {
+    type Item = usize;
+
+    #[inline]
+    fn next(&mut self) -> Option<usize> {
+        match self.inner.next() {
+            Some((i, _)) => Some(i),
+            None => self.end.take(),
+        }
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let (lower, upper) = self.inner.size_hint();
+        let end = self.end.is_some() as usize;
+        (lower.saturating_add(end), upper.and_then(|upper| upper.checked_add(end)))
+    }
+
+    #[inline]
+    fn last(mut self) -> Option<usize> {
+        // No need to go through the entire string.
+        self.next_back()
+    }
 }
 
+#[unstable(feature = "str_char_boundary_indices", issue = "none")]
+impl<'a> DoubleEndedIterator for CharBoundaryIndices<'a> {
+    #[inline]
+    fn next_back(&mut self) -> Option<usize> {
+        match self.end.take() {
+            Some(end) => Some(end),
+            None => self.inner.next_back().map(|(i, _)| i),
+        }
+    }
+}
+
+#[unstable(feature = "str_char_boundary_indices", issue = "none")]
+impl FusedIterator for CharBoundaryIndices<'_> {}
+
 /// An iterator over the bytes of a string slice.
 ///
 /// This struct is created by the [`bytes`] method on [`str`].
@@ -257,6 +477,23 @@ fn nth(&mut self, n: usize) -> Option<Self::Item> {
         self.0.nth(n)
     }
 
+    #[inline]
+    fn try_fold<B, F, R>(&mut self, init: B, f: F) -> R
+    where
+        F: FnMut(B, Self::Item) -> R,
+        R: Try<Output = B>,
+    {
+        self.0.try_fold(init, f)
+    }
+
+    #[inline]
+    fn fold<B, F>(self, init: B, f: F) -> B
+    where
+        F: FnMut(B, Self::Item) -> B,
+    {
+        self.0.fold(init, f)
+    }
+
     #[inline]
     fn all<F>(&mut self, f: F) -> bool
     where
@@ -317,6 +554,23 @@ fn nth_back(&mut self, n: usize) -> Option<Self::Item> {
         self.0.nth_back(n)
     }
 
+    #[inline]
+    fn try_rfold<B, F, R>(&mut self, init: B, f: F) -> R
+    where
+        F: FnMut(B, Self::Item) -> R,
+        R: Try<Output = B>,
+    {
+        self.0.try_rfold(init, f)
+    }
+
+    #[inline]
+    fn rfold<B, F>(self, init: B, f: F) -> B
+    where
+        F: FnMut(B, Self::Item) -> B,
+    {
+        self.0.rfold(init, f)
+    }
+
     #[inline]
     fn rfind<P>(&mut self, predicate: P) -> Option<Self::Item>
     where
@@ -355,6 +609,54 @@ unsafe impl TrustedRandomAccessNoCoerce for Bytes<'_> {
     const MAY_HAVE_SIDE_EFFECT: bool = false;
 }
 
+/// An iterator over a string slice in chunks of at most `max_len` bytes,
+/// each ending on a char boundary.
+///
+/// This struct is created by the [`split_byte_chunks`] method on [`str`].
+/// See its documentation for more.
+///
+/// [`split_byte_chunks`]: str::split_byte_chunks
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+#[unstable(feature = "str_split_byte_chunks", issue = "none")]
+#[derive(Clone, Debug)]
+pub struct SplitByteChunks<'a> {
+    pub(super) v: &'a str,
+    pub(super) max_len: usize,
+}
+
+#[unstable(feature = "str_split_byte_chunks", issue = "none")]
+impl<'a> Iterator for SplitByteChunks<'a> {
+    type Item = &'a str;
+
+    #[inline]
+    fn next(&mut self) -> Option<&'a str> {
+        if self.v.is_empty() {
+            return None;
+        }
+
+        let mut mid = self.v.floor_char_boundary(self.max_len);
+        if mid == 0 {
+            // The first char alone is longer than `max_len`, so yield it by itself.
+            mid = self.v.ceil_char_boundary(1);
+        }
+        // SAFETY: `mid` is a char boundary in `1..=self.v.len()`.
+        let (chunk, rest) = unsafe { (self.v.get_unchecked(..mid), self.v.get_unchecked(mid..)) };
+        self.v = rest;
+        Some(chunk)
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        // Each chunk holds at least one char, and at most `max_len` bytes
+        // unless it is a single char of up to four bytes.
+        let len = self.v.len();
+        (len.div_ceil(cmp::max(self.max_len, 4)), Some(len))
+    }
+}
+
+#[unstable(feature = "str_split_byte_chunks", issue = "none")]
+impl FusedIterator for SplitByteChunks<'_> {}
+
 /// This macro generates a Clone impl for string pattern API
 /// wrapper types of the form X<'a, P>
 macro_rules! derive_pattern_clone {
@@ -409,6 +711,13 @@ fn clone(&self) -> Self {
 /// so the two wrapper structs implement `Iterator`
 /// and `DoubleEndedIterator` depending on the concrete pattern type, leading
 /// to the complex impls seen above.
+///
+/// If the internal iterator also has forward and reverse `try_fold` methods,
+/// they can be named with `folding with`, and the generated `Iterator` impls
+/// will forward `try_fold` and `fold` to them. Likewise, forward and reverse
+/// `count` methods can be named with `counting with`, forward and reverse
+/// `size_hint` methods with `sizing with`, and forward and reverse `nth`
+/// methods with `skipping with`.
 macro_rules! generate_pattern_iterators {
     {
         // Forward iterator
@@ -425,9 +734,14 @@ macro_rules! generate_pattern_iterators {
         stability:
             $(#[$common_stability_attribute:meta])*
 
-        // Internal almost-iterator that is being delegated to
+        // Internal almost-iterator that is being delegated to, optionally
+        // along with the methods that `try_fold` and `count` are forwarded to
         internal:
-            $internal_iterator:ident yielding ($iterty:ty);
+            $internal_iterator:ident yielding ($iterty:ty)
+                $(, folding with $try_fold:ident and $try_rfold:ident)?
+                $(, counting with $count:ident and $rcount:ident)?
+                $(, sizing with $size_hint:ident and $rsize_hint:ident)?
+                $(, skipping with $nth:ident and $rnth:ident)?;
 
         // Kind of delegation - either single ended or double ended
         delegate $($t:tt)*
@@ -456,6 +770,47 @@ impl<'a, P: Pattern<'a>> Iterator for $forward_iterator<'a, P> {
             fn next(&mut self) -> Option<$iterty> {
                 self.0.next()
             }
+
+            $(
+                #[inline]
+                fn try_fold<B, F, R>(&mut self, init: B, f: F) -> R
+                where
+                    Self: Sized,
+                    F: FnMut(B, Self::Item) -> R,
+                    R: Try<Output = B>,
+                {
+                    self.0.$try_fold(init, f)
+                }
+
+                #[inline]
+                fn fold<B, F>(mut self, init: B, f: F) -> B
+                where
+                    F: FnMut(B, Self::Item) -> B,
+                {
+                    self.0.$try_fold(init, NeverShortCircuit::wrap_mut_2(f)).0
+                }
+            )?
+
+            $(
+                #[inline]
+                fn count(self) -> usize {
+                    self.0.$count()
+                }
+            )?
+
+            $(
+                #[inline]
+                fn size_hint(&self) -> (usize, Option<usize>) {
+                    self.0.$size_hint()
+                }
+            )?
+
+            $(
+                #[inline]
+                fn nth(&mut self, n: usize) -> Option<$iterty> {
+                    self.0.$nth(n)
+                }
+            )?
         }
 
         $(#[$common_stability_attribute])*
@@ -495,6 +850,47 @@ impl<'a, P> Iterator for $reverse_iterator<'a, P>
             fn next(&mut self) -> Option<$iterty> {
                 self.0.next_back()
             }
+
+            $(
+                #[inline]
+                fn try_fold<B, F, R>(&mut self, init: B, f: F) -> R
+                where
+                    Self: Sized,
+                    F: FnMut(B, Self::Item) -> R,
+                    R: Try<Output = B>,
+                {
+                    self.0.$try_rfold(init, f)
+                }
+
+                #[inline]
+                fn fold<B, F>(mut self, init: B, f: F) -> B
+                where
+                    F: FnMut(B, Self::Item) -> B,
+                {
+                    self.0.$try_rfold(init, NeverShortCircuit::wrap_mut_2(f)).0
+                }
+            )?
+
+            $(
+                #[inline]
+                fn count(self) -> usize {
+                    self.0.$rcount()
+                }
+            )?
+
+            $(
+                #[inline]
+                fn size_hint(&self) -> (usize, Option<usize>) {
+                    self.0.$rsize_hint()
+                }
+            )?
+
+            $(
+                #[inline]
+                fn nth(&mut self, n: usize) -> Option<$iterty> {
+                    self.0.$rnth(n)
+                }
+            )?
         }
 
         $(#[$common_stability_attribute])*
@@ -615,6 +1011,53 @@ fn next(&mut self) -> Option<&'a str> {
         }
     }
 
+    #[inline]
+    fn try_fold<B, F, R>(&mut self, init: B, mut f: F) -> R
+    where
+        F: FnMut(B, &'a str) -> R,
+        R: Try<Output = B>,
+    {
+        // Equivalent to calling `next` until it returns `None`, but drives the
+        // searcher directly instead of re-checking `finished` every time.
+        let mut acc = init;
+        if self.finished {
+            return R::from_output(acc);
+        }
+
+        let haystack = self.matcher.haystack();
+        while let Some((a, b)) = self.matcher.next_match() {
+            // SAFETY: `Searcher` guarantees that `a` and `b` lie on unicode boundaries.
+            let elt = unsafe { haystack.get_unchecked(self.start..a) };
+            self.start = b;
+            acc = f(acc, elt)?;
+        }
+        match self.get_end() {
+            Some(elt) => f(acc, elt),
+            None => R::from_output(acc),
+        }
+    }
+
+    #[inline]
+    fn nth(&mut self, mut n: usize) -> Option<&'a str> {
+        // Equivalent to calling `next` `n + 1` times, but only creates the
+        // last substring.
+        if self.finished {
+            return None;
+        }
+
+        while n > 0 {
+            match self.matcher.next_match() {
+                Some((_, b)) => self.start = b,
+                None => {
+                    self.finished = true;
+                    return None;
+                }
+            }
+            n -= 1;
+        }
+        self.next()
+    }
+
     #[inline]
     fn next_inclusive(&mut self) -> Option<&'a str> {
         if self.finished {
@@ -672,6 +1115,103 @@ fn next_back(&mut self) -> Option<&'a str>
         }
     }
 
+    #[inline]
+    fn nth_back(&mut self, mut n: usize) -> Option<&'a str>
+    where
+        P::Searcher: ReverseSearcher<'a>,
+    {
+        // Equivalent to calling `next_back` `n + 1` times, but only creates
+        // the last substring.
+        if self.finished {
+            return None;
+        }
+
+        if !self.allow_trailing_empty {
+            // Let `next_back` take care of skipping an empty trailing substring.
+            let elt = self.next_back()?;
+            if n == 0 {
+                return Some(elt);
+            }
+            n -= 1;
+            if self.finished {
+                return None;
+            }
+        }
+
+        while n > 0 {
+            match self.matcher.next_match_back() {
+                Some((a, _)) => self.end = a,
+                None => {
+                    self.finished = true;
+                    return None;
+                }
+            }
+            n -= 1;
+        }
+        self.next_back()
+    }
+
+    #[inline]
+    fn try_rfold<B, F, R>(&mut self, init: B, mut f: F) -> R
+    where
+        P::Searcher: ReverseSearcher<'a>,
+        F: FnMut(B, &'a str) -> R,
+        R: Try<Output = B>,
+    {
+        // Equivalent to calling `next_back` until it returns `None`.
+        let mut acc = init;
+        if !self.finished && !self.allow_trailing_empty {
+            // Let `next_back` take care of skipping an empty trailing substring.
+            if let Some(elt) = self.next_back() {
+                acc = f(acc, elt)?;
+            }
+        }
+        if self.finished {
+            return R::from_output(acc);
+        }
+
+        let haystack = self.matcher.haystack();
+        while let Some((a, b)) = self.matcher.next_match_back() {
+            // SAFETY: `Searcher` guarantees that `a` and `b` lie on unicode boundaries.
+            let elt = unsafe { haystack.get_unchecked(b..self.end) };
+            self.end = a;
+            acc = f(acc, elt)?;
+        }
+        self.finished = true;
+        // SAFETY: `self.start` and `self.end` always lie on unicode boundaries.
+        f(acc, unsafe { haystack.get_unchecked(self.start..self.end) })
+    }
+
+    #[inline]
+    fn position_inclusive<F>(&mut self, mut predicate: F) -> Option<usize>
+    where
+        F: FnMut(&'a str) -> bool,
+    {
+        // Equivalent to calling `next_inclusive` until `predicate` returns
+        // `true`, but drives the searcher directly.
+        if self.finished {
+            return None;
+        }
+
+        let haystack = self.matcher.haystack();
+        let mut i = 0;
+        while let Some((_, b)) = self.matcher.next_match() {
+            // SAFETY: `Searcher` guarantees that `b` lies on unicode boundary,
+            // and self.start is either the start of the original string,
+            // or `b` was assigned to it, so it also lies on unicode boundary.
+            let elt = unsafe { haystack.get_unchecked(self.start..b) };
+            self.start = b;
+            if predicate(elt) {
+                return Some(i);
+            }
+            i += 1;
+        }
+        match self.get_end() {
+            Some(elt) if predicate(elt) => Some(i),
+            _ => None,
+        }
+    }
+
     #[inline]
     fn next_back_inclusive(&mut self) -> Option<&'a str>
     where
@@ -715,6 +1255,40 @@ fn next_back_inclusive(&mut self) -> Option<&'a str>
         }
     }
 
+    #[inline]
+    fn try_rfold_inclusive<B, F, R>(&mut self, init: B, mut f: F) -> R
+    where
+        P::Searcher: ReverseSearcher<'a>,
+        F: FnMut(B, &'a str) -> R,
+        R: Try<Output = B>,
+    {
+        // Equivalent to calling `next_back_inclusive` until it returns `None`.
+        let mut acc = init;
+        if !self.finished && !self.allow_trailing_empty {
+            // Let `next_back_inclusive` take care of skipping an empty
+            // trailing substring.
+            if let Some(elt) = self.next_back_inclusive() {
+                acc = f(acc, elt)?;
+            }
+        }
+        if self.finished {
+            return R::from_output(acc);
+        }
+
+        let haystack = self.matcher.haystack();
+        while let Some((_, b)) = self.matcher.next_match_back() {
+            // SAFETY: `Searcher` guarantees that `b` lies on unicode boundary,
+            // and self.end is either the end of the original string,
+            // or `b` was assigned to it, so it also lies on unicode boundary.
+            let elt = unsafe { haystack.get_unchecked(b..self.end) };
+            self.end = b;
+            acc = f(acc, elt)?;
+        }
+        self.finished = true;
+        // SAFETY: `self.start` and `self.end` always lie on unicode boundaries.
+        f(acc, unsafe { haystack.get_unchecked(self.start..self.end) })
+    }
+
     #[inline]
     fn as_str(&self) -> &'a str {
         // `Self::get_end` doesn't change `self.start`
@@ -725,7 +1299,96 @@ fn as_str(&self) -> &'a str {
         // SAFETY: `self.start` and `self.end` always lie on unicode boundaries.
         unsafe { self.matcher.haystack().get_unchecked(self.start..self.end) }
     }
-}
+
+    /// Counts the remaining pieces without running the searcher, for patterns
+    /// that match exactly the occurrences of the ASCII byte `byte`.
+    #[inline]
+    fn count_byte_separated(self, byte: u8) -> usize {
+        debug_assert!(byte.is_ascii());
+        if self.finished {
+            return 0;
+        }
+
+        // The unsearched part of the haystack holds one more piece than it
+        // has separators.
+        let mut rest = self.as_str().as_bytes();
+        let mut count = 1;
+        while let Some(i) = slice::memchr::memchr(byte, rest) {
+            count += 1;
+            rest = &rest[i + 1..];
+        }
+
+        // `rest` is now the last piece, which is skipped if empty unless
+        // trailing empty pieces are allowed.
+        if !self.allow_trailing_empty && rest.is_empty() {
+            count -= 1;
+        }
+        count
+    }
+}
+
+/// Counts the remaining pieces of a split, scanning for the separator byte
+/// directly when splitting on an ASCII `char`.
+trait SplitCount {
+    fn split_count(self) -> usize;
+}
+
+impl<'a, P: Pattern<'a>> SplitCount for SplitInternal<'a, P> {
+    #[inline]
+    default fn split_count(mut self) -> usize {
+        let mut count = 0;
+        while self.next().is_some() {
+            count += 1;
+        }
+        count
+    }
+}
+
+impl<'a> SplitCount for SplitInternal<'a, char> {
+    #[inline]
+    fn split_count(mut self) -> usize {
+        if let Some(byte) = self.matcher.ascii_needle() {
+            return self.count_byte_separated(byte);
+        }
+        let mut count = 0;
+        while self.next().is_some() {
+            count += 1;
+        }
+        count
+    }
+}
+
+/// Computes the `size_hint` of a split, which has an upper bound when
+/// splitting on an empty `&str`.
+trait SplitSizeHint {
+    fn split_size_hint(&self) -> (usize, Option<usize>);
+}
+
+impl<'a, P: Pattern<'a>> SplitSizeHint for SplitInternal<'a, P> {
+    #[inline]
+    default fn split_size_hint(&self) -> (usize, Option<usize>) {
+        if self.finished { (0, Some(0)) } else { (0, None) }
+    }
+}
+
+impl<'a, 'b> SplitSizeHint for SplitInternal<'a, &'b str> {
+    #[inline]
+    fn split_size_hint(&self) -> (usize, Option<usize>) {
+        if self.finished {
+            return (0, Some(0));
+        }
+        match self.matcher.empty_needle_match_bounds() {
+            // Every remaining match ends a piece, and the piece after the
+            // last match is empty, so it only counts if trailing empty pieces
+            // are allowed.
+            Some((lower, upper)) => {
+                let trailing = self.allow_trailing_empty as usize;
+                (lower + trailing, upper.map(|upper| upper + trailing))
+            }
+            None => (0, None),
+        }
+    }
+}
 
 generate_pattern_iterators! {
     forward:
@@ -741,7 +1404,10 @@ fn as_str(&self) -> &'a str {
     stability:
         #[stable(feature = "rust1", since = "1.0.0")]
     internal:
-        SplitInternal yielding (&'a str);
+        SplitInternal yielding (&'a str), folding with try_fold and try_rfold,
+            counting with split_count and split_count,
+            sizing with split_size_hint and split_size_hint,
+            skipping with nth and nth_back;
     delegate double ended;
 }
 
@@ -764,6 +1430,48 @@ impl<'a, P: Pattern<'a>> Split<'a, P> {
     pub fn as_str(&self) -> &'a str {
         self.0.as_str()
     }
+
+    /// Returns an iterator over each pair of adjacent pieces of the split.
+    ///
+    /// A split that yields only one piece yields no pairs.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(str_split_pairs)]
+    /// let v: Vec<(&str, &str)> = "a,b,c".split(',').pairs().collect();
+    /// assert_eq!(v, [("a", "b"), ("b", "c")]);
+    ///
+    /// assert_eq!("abc".split(',').pairs().next(), None);
+    /// ```
+    #[inline]
+    #[unstable(feature = "str_split_pairs", issue = "none")]
+    pub fn pairs(self) -> SplitPairs<'a, P> {
+        SplitPairs { inner: self, prev: None }
+    }
+
+    /// Pairs each piece of the split with its starting byte offset in the
+    /// original string.
+    ///
+    /// Combine with [`enumerate`] to also get the index of each piece.
+    ///
+    /// [`enumerate`]: Iterator::enumerate
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(str_split_with_offsets)]
+    /// let v: Vec<(usize, &str)> = "a,bb,c".split(',').with_offsets().collect();
+    /// assert_eq!(v, [(0, "a"), (2, "bb"), (5, "c")]);
+    ///
+    /// let v: Vec<(usize, &str)> = ",a,".split(',').with_offsets().rev().collect();
+    /// assert_eq!(v, [(3, ""), (1, "a"), (0, "")]);
+    /// ```
+    #[inline]
+    #[unstable(feature = "str_split_with_offsets", issue = "none")]
+    pub fn with_offsets(self) -> SplitWithOffsets<'a, P> {
+        SplitWithOffsets { inner: self }
+    }
 }
 
 impl<'a, P: Pattern<'a>> RSplit<'a, P> {
@@ -787,6 +1495,65 @@ pub fn as_str(&self) -> &'a str {
     }
 }
 
+derive_pattern_clone! {
+    clone SplitRangesInternal
+    with |s| SplitRangesInternal(s.0.clone())
+}
+
+pub(super) struct SplitRangesInternal<'a, P: Pattern<'a>>(pub(super) SplitInternal<'a, P>);
+
+impl<'a, P> fmt::Debug for SplitRangesInternal<'a, P>
+where
+    P: Pattern<'a, Searcher: fmt::Debug>,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("SplitRangesInternal").field(&self.0).finish()
+    }
+}
+
+impl<'a, P: Pattern<'a>> SplitRangesInternal<'a, P> {
+    #[inline]
+    fn range_of(&self, piece: &'a str) -> Range<usize> {
+        // Every piece is a sub-slice of the haystack, so the difference between
+        // the two start pointers is the piece's byte offset.
+        let start = piece.as_ptr() as usize - self.0.matcher.haystack().as_ptr() as usize;
+        start..start + piece.len()
+    }
+
+    #[inline]
+    fn next(&mut self) -> Option<Range<usize>> {
+        let piece = self.0.next()?;
+        Some(self.range_of(piece))
+    }
+
+    #[inline]
+    fn next_back(&mut self) -> Option<Range<usize>>
+    where
+        P::Searcher: ReverseSearcher<'a>,
+    {
+        let piece = self.0.next_back()?;
+        Some(self.range_of(piece))
+    }
+}
+
+generate_pattern_iterators! {
+    forward:
+        /// Created with the method [`split_ranges`].
+        ///
+        /// [`split_ranges`]: str::split_ranges
+        struct SplitRanges;
+    reverse:
+        /// Created with the method [`rsplit_ranges`].
+        ///
+        /// [`rsplit_ranges`]: str::rsplit_ranges
+        struct RSplitRanges;
+    stability:
+        #[unstable(feature = "str_split_ranges", issue = "none")]
+    internal:
+        SplitRangesInternal yielding (Range<usize>);
+    delegate double ended;
+}
+
 generate_pattern_iterators! {
     forward:
         /// Created with the method [`split_terminator`].
@@ -801,7 +1568,10 @@ pub fn as_str(&self) -> &'a str {
     stability:
         #[stable(feature = "rust1", since = "1.0.0")]
     internal:
-        SplitInternal yielding (&'a str);
+        SplitInternal yielding (&'a str), folding with try_fold and try_rfold,
+            counting with split_count and split_count,
+            sizing with split_size_hint and split_size_hint,
+            skipping with nth and nth_back;
     delegate double ended;
 }
 
@@ -1025,30 +1795,95 @@ fn next_back(&mut self) -> Option<(usize, &'a str)>
     delegate double ended;
 }
 
+derive_pattern_clone! {
+    clone MatchRangesInternal
+    with |s| MatchRangesInternal(s.0.clone())
+}
+
+pub(super) struct MatchRangesInternal<'a, P: Pattern<'a>>(pub(super) P::Searcher);
+
+impl<'a, P> fmt::Debug for MatchRangesInternal<'a, P>
+where
+    P: Pattern<'a, Searcher: fmt::Debug>,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("MatchRangesInternal").field(&self.0).finish()
+    }
+}
+
+impl<'a, P: Pattern<'a>> MatchRangesInternal<'a, P> {
+    #[inline]
+    fn next(&mut self) -> Option<Range<usize>> {
+        self.0.next_match().map(|(start, end)| start..end)
+    }
+
+    #[inline]
+    fn next_back(&mut self) -> Option<Range<usize>>
+    where
+        P::Searcher: ReverseSearcher<'a>,
+    {
+        self.0.next_match_back().map(|(start, end)| start..end)
+    }
+}
+
+generate_pattern_iterators! {
+    forward:
+        /// Created with the method [`match_ranges`].
+        ///
+        /// [`match_ranges`]: str::match_ranges
+        struct MatchRanges;
+    reverse:
+        /// Created with the method [`rmatch_ranges`].
+        ///
+        /// [`rmatch_ranges`]: str::rmatch_ranges
+        struct RMatchRanges;
+    stability:
+        #[unstable(feature = "str_match_ranges", issue = "none")]
+    internal:
+        MatchRangesInternal yielding (Range<usize>);
+    delegate double ended;
+}
+
 derive_pattern_clone! {
     clone MatchesInternal
-    with |s| MatchesInternal(s.0.clone())
+    with |s| MatchesInternal { matcher: s.matcher.clone(), ..*s }
 }
 
-pub(super) struct MatchesInternal<'a, P: Pattern<'a>>(pub(super) P::Searcher);
+pub(super) struct MatchesInternal<'a, P: Pattern<'a>> {
+    /// Start of the not yet searched part of the haystack
+    pub(super) start: usize,
+    /// End of the not yet searched part of the haystack
+    pub(super) end: usize,
+    pub(super) matcher: P::Searcher,
+}
 
 impl<'a, P> fmt::Debug for MatchesInternal<'a, P>
 where
     P: Pattern<'a, Searcher: fmt::Debug>,
 {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        f.debug_tuple("MatchesInternal").field(&self.0).finish()
+        f.debug_struct("MatchesInternal")
+            .field("start", &self.start)
+            .field("end", &self.end)
+            .field("matcher", &self.matcher)
+            .finish()
     }
 }
 
 impl<'a, P: Pattern<'a>> MatchesInternal<'a, P> {
     #[inline]
     fn next(&mut self) -> Option<&'a str> {
-        // SAFETY: `Searcher` guarantees that `start` and `end` lie on unicode boundaries.
-        self.0.next_match().map(|(a, b)| unsafe {
-            // Indices are known to be on utf8 boundaries
-            self.0.haystack().get_unchecked(a..b)
-        })
+        match self.matcher.next_match() {
+            Some((a, b)) => {
+                self.start = b;
+                // SAFETY: `Searcher` guarantees that `a` and `b` lie on unicode boundaries.
+                Some(unsafe { self.matcher.haystack().get_unchecked(a..b) })
+            }
+            None => {
+                self.start = self.end;
+                None
+            }
+        }
     }
 
     #[inline]
@@ -1056,11 +1891,45 @@ fn next_back(&mut self) -> Option<&'a str>
     where
         P::Searcher: ReverseSearcher<'a>,
     {
-        // SAFETY: `Searcher` guarantees that `start` and `end` lie on unicode boundaries.
-        self.0.next_match_back().map(|(a, b)| unsafe {
-            // Indices are known to be on utf8 boundaries
-            self.0.haystack().get_unchecked(a..b)
-        })
+        match self.matcher.next_match_back() {
+            Some((a, b)) => {
+                self.end = a;
+                // SAFETY: `Searcher` guarantees that `a` and `b` lie on unicode boundaries.
+                Some(unsafe { self.matcher.haystack().get_unchecked(a..b) })
+            }
+            None => {
+                self.end = self.start;
+                None
+            }
+        }
+    }
+
+    #[inline]
+    fn count(mut self) -> usize {
+        // Only the number of matches is needed, so skip building the subslices.
+        let mut count = 0;
+        while self.matcher.next_match().is_some() {
+            count += 1;
+        }
+        count
+    }
+
+    #[inline]
+    fn rcount(mut self) -> usize
+    where
+        P::Searcher: ReverseSearcher<'a>,
+    {
+        let mut count = 0;
+        while self.matcher.next_match_back().is_some() {
+            count += 1;
+        }
+        count
+    }
+
+    #[inline]
+    fn as_str(&self) -> &'a str {
+        // SAFETY: `self.start` and `self.end` always lie on unicode boundaries.
+        unsafe { self.matcher.haystack().get_unchecked(self.start..self.end) }
     }
 }
 
@@ -1078,10 +1947,154 @@ fn next_back(&mut self) -> Option<&'a str>
     stability:
         #[stable(feature = "str_matches", since = "1.2.0")]
     internal:
-        MatchesInternal yielding (&'a str);
+        MatchesInternal yielding (&'a str), counting with count and rcount;
     delegate double ended;
 }
 
+impl<'a, P: Pattern<'a>> Matches<'a, P> {
+    /// Returns the part of the haystack that has not been searched yet.
+    ///
+    /// If the iterator has also been advanced from the back, the part
+    /// already searched from that end is excluded as well.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(str_matches_as_str)]
+    /// let mut matches = "a1b2c3".matches(char::is_numeric);
+    /// assert_eq!(matches.as_str(), "a1b2c3");
+    ///
+    /// matches.next();
+    /// assert_eq!(matches.as_str(), "b2c3");
+    ///
+    /// matches.by_ref().for_each(drop);
+    /// assert_eq!(matches.as_str(), "");
+    /// ```
+    #[inline]
+    #[unstable(feature = "str_matches_as_str", issue = "none")]
+    pub fn as_str(&self) -> &'a str {
+        self.0.as_str()
+    }
+
+    /// Limits the iterator to yielding at most `n` matches.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(str_matches_max_items)]
+    /// let v: Vec<&str> = "a1b2c3".matches(char::is_numeric).max_items(2).collect();
+    /// assert_eq!(v, ["1", "2"]);
+    /// ```
+    #[inline]
+    #[unstable(feature = "str_matches_max_items", issue = "none")]
+    pub fn max_items(self, n: usize) -> MatchesN<'a, P> {
+        MatchesN(MatchesNInternal { iter: self.0, count: n })
+    }
+}
+
+impl<'a, P: Pattern<'a>> RMatches<'a, P> {
+    /// Returns the part of the haystack that has not been searched yet.
+    ///
+    /// Since the search runs from the end of the haystack, this is the
+    /// prefix that precedes the last yielded match.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(str_matches_as_str)]
+    /// let mut matches = "a1b2".rmatches(char::is_numeric);
+    /// assert_eq!(matches.as_str(), "a1b2");
+    ///
+    /// assert_eq!(matches.next(), Some("2"));
+    /// assert_eq!(matches.as_str(), "a1b");
+    ///
+    /// matches.by_ref().for_each(drop);
+    /// assert_eq!(matches.as_str(), "");
+    /// ```
+    #[inline]
+    #[unstable(feature = "str_matches_as_str", issue = "none")]
+    pub fn as_str(&self) -> &'a str {
+        self.0.as_str()
+    }
+
+    /// Limits the iterator to yielding at most `n` matches.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(str_matches_max_items)]
+    /// let v: Vec<&str> = "a1b2c3".rmatches(char::is_numeric).max_items(2).collect();
+    /// assert_eq!(v, ["3", "2"]);
+    /// ```
+    #[inline]
+    #[unstable(feature = "str_matches_max_items", issue = "none")]
+    pub fn max_items(self, n: usize) -> RMatchesN<'a, P> {
+        RMatchesN(MatchesNInternal { iter: self.0, count: n })
+    }
+}
+
+derive_pattern_clone! {
+    clone MatchesNInternal
+    with |s| MatchesNInternal { iter: s.iter.clone(), ..*s }
+}
+
+pub(super) struct MatchesNInternal<'a, P: Pattern<'a>> {
+    pub(super) iter: MatchesInternal<'a, P>,
+    /// The number of matches remaining
+    pub(super) count: usize,
+}
+
+impl<'a, P> fmt::Debug for MatchesNInternal<'a, P>
+where
+    P: Pattern<'a, Searcher: fmt::Debug>,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("MatchesNInternal")
+            .field("iter", &self.iter)
+            .field("count", &self.count)
+            .finish()
+    }
+}
+
+impl<'a, P: Pattern<'a>> MatchesNInternal<'a, P> {
+    #[inline]
+    fn next(&mut self) -> Option<&'a str> {
+        // Unlike `SplitNInternal`, there is no remainder to yield once the
+        // count runs out, so we simply stop.
+        if self.count == 0 {
+            return None;
+        }
+        self.count -= 1;
+        self.iter.next()
+    }
+
+    #[inline]
+    fn next_back(&mut self) -> Option<&'a str>
+    where
+        P::Searcher: ReverseSearcher<'a>,
+    {
+        if self.count == 0 {
+            return None;
+        }
+        self.count -= 1;
+        self.iter.next_back()
+    }
+}
+
+generate_pattern_iterators! {
+    forward:
+        /// Created with the method [`Matches::max_items`].
+        struct MatchesN;
+    reverse:
+        /// Created with the method [`RMatches::max_items`].
+        struct RMatchesN;
+    stability:
+        #[unstable(feature = "str_matches_max_items", issue = "none")]
+    internal:
+        MatchesNInternal yielding (&'a str);
+    delegate single ended;
+}
+
 /// An iterator over the lines of a string, as string slices.
 ///
 /// This struct is created with the [`lines`] method on [`str`].
@@ -1107,6 +2120,12 @@ fn size_hint(&self) -> (usize, Option<usize>) {
         self.0.size_hint()
     }
 
+    #[inline]
+    fn count(self) -> usize {
+        // Stripping a trailing `\r` never changes the number of lines.
+        self.0.iter.0.count_byte_separated(b'\n')
+    }
+
     #[inline]
     fn last(mut self) -> Option<&'a str> {
         self.next_back()
@@ -1163,17 +2182,128 @@ fn next_back(&mut self) -> Option<&'a str> {
 #[allow(deprecated)]
 impl FusedIterator for LinesAny<'_> {}
 
-/// An iterator over the non-whitespace substrings of a string,
-/// separated by any amount of whitespace.
+/// An iterator over the lines of a string, ended only by `\n`.
 ///
-/// This struct is created by the [`split_whitespace`] method on [`str`].
+/// This struct is created with the [`lines_lf`] method on [`str`].
 /// See its documentation for more.
 ///
-/// [`split_whitespace`]: str::split_whitespace
-#[stable(feature = "split_whitespace", since = "1.1.0")]
+/// [`lines_lf`]: str::lines_lf
+#[unstable(feature = "str_lines_lf", issue = "none")]
+#[must_use = "iterators are lazy and do nothing unless consumed"]
 #[derive(Clone, Debug)]
-pub struct SplitWhitespace<'a> {
-    pub(super) inner: Filter<Split<'a, IsWhitespace>, IsNotEmpty>,
+pub struct LinesLf<'a>(pub(super) SplitTerminator<'a, char>);
+
+#[unstable(feature = "str_lines_lf", issue = "none")]
+impl<'a> Iterator for LinesLf<'a> {
+    type Item = &'a str;
+
+    #[inline]
+    fn next(&mut self) -> Option<&'a str> {
+        self.0.next()
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.0.size_hint()
+    }
+
+    #[inline]
+    fn count(self) -> usize {
+        self.0.count()
+    }
+
+    #[inline]
+    fn last(mut self) -> Option<&'a str> {
+        self.next_back()
+    }
+}
+
+#[unstable(feature = "str_lines_lf", issue = "none")]
+impl<'a> DoubleEndedIterator for LinesLf<'a> {
+    #[inline]
+    fn next_back(&mut self) -> Option<&'a str> {
+        self.0.next_back()
+    }
+}
+
+#[unstable(feature = "str_lines_lf", issue = "none")]
+impl FusedIterator for LinesLf<'_> {}
+
+/// An iterator over the lines of a string, paired with their line numbers.
+///
+/// This struct is created with the [`numbered_lines`] method on [`str`].
+/// See its documentation for more.
+///
+/// [`numbered_lines`]: str::numbered_lines
+#[unstable(feature = "str_numbered_lines", issue = "none")]
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+#[derive(Clone, Debug)]
+pub struct NumberedLines<'a> {
+    pub(super) inner: Lines<'a>,
+    /// The number of the next line from the front
+    pub(super) front: usize,
+    /// The number of the next line from the back, once it has been counted
+    pub(super) back: Option<usize>,
+}
+
+#[unstable(feature = "str_numbered_lines", issue = "none")]
+impl<'a> Iterator for NumberedLines<'a> {
+    type Item = (usize, &'a str);
+
+    #[inline]
+    fn next(&mut self) -> Option<(usize, &'a str)> {
+        let line = self.inner.next()?;
+        let number = self.front;
+        self.front += 1;
+        Some((number, line))
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.inner.size_hint()
+    }
+
+    #[inline]
+    fn count(self) -> usize {
+        self.inner.count()
+    }
+
+    #[inline]
+    fn last(mut self) -> Option<(usize, &'a str)> {
+        self.next_back()
+    }
+}
+
+#[unstable(feature = "str_numbered_lines", issue = "none")]
+impl<'a> DoubleEndedIterator for NumberedLines<'a> {
+    #[inline]
+    fn next_back(&mut self) -> Option<(usize, &'a str)> {
+        let line = self.inner.next_back()?;
+        // The first line taken from the back needs the number of lines still
+        // left, so they are counted once here.
+        let number = match self.back {
+            Some(number) => number,
+            None => self.front + self.inner.clone().count(),
+        };
+        self.back = Some(number - 1);
+        Some((number, line))
+    }
+}
+
+#[unstable(feature = "str_numbered_lines", issue = "none")]
+impl FusedIterator for NumberedLines<'_> {}
+
+/// An iterator over the non-whitespace substrings of a string,
+/// separated by any amount of whitespace.
+///
+/// This struct is created by the [`split_whitespace`] method on [`str`].
+/// See its documentation for more.
+///
+/// [`split_whitespace`]: str::split_whitespace
+#[stable(feature = "split_whitespace", since = "1.1.0")]
+#[derive(Clone, Debug)]
+pub struct SplitWhitespace<'a> {
+    pub(super) inner: Filter<Split<'a, IsWhitespace>, IsNotEmpty>,
 }
 
 /// An iterator over the non-ASCII-whitespace substrings of a string,
@@ -1276,6 +2406,20 @@ fn size_hint(&self) -> (usize, Option<usize>) {
     fn last(mut self) -> Option<&'a str> {
         self.next_back()
     }
+
+    #[inline]
+    fn count(self) -> usize {
+        // Each remaining word is a maximal run of non-whitespace bytes, so
+        // count where those runs start instead of creating the slices.
+        let mut in_word = false;
+        let mut count = 0;
+        for &byte in self.as_str().as_bytes() {
+            let is_word = !byte.is_ascii_whitespace();
+            count += (is_word && !in_word) as usize;
+            in_word = is_word;
+        }
+        count
+    }
 }
 
 #[stable(feature = "split_ascii_whitespace", since = "1.34.0")]
@@ -1319,6 +2463,61 @@ pub fn as_str(&self) -> &'a str {
     }
 }
 
+/// An iterator over the non-whitespace substrings of a string and their
+/// starting byte offsets, separated by any amount of whitespace.
+///
+/// This struct is created by the [`split_whitespace_indices`] method on [`str`].
+/// See its documentation for more.
+///
+/// [`split_whitespace_indices`]: str::split_whitespace_indices
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+#[unstable(feature = "str_split_whitespace_indices", issue = "none")]
+#[derive(Clone, Debug)]
+pub struct SplitWhitespaceIndices<'a> {
+    pub(super) haystack: &'a str,
+    pub(super) inner: SplitWhitespace<'a>,
+}
+
+impl<'a> SplitWhitespaceIndices<'a> {
+    #[inline]
+    fn with_offset(&self, token: &'a str) -> (usize, &'a str) {
+        // Every token is a sub-slice of `haystack`, so the difference between
+        // the two start pointers is the token's byte offset.
+        (token.as_ptr() as usize - self.haystack.as_ptr() as usize, token)
+    }
+}
+
+#[unstable(feature = "str_split_whitespace_indices", issue = "none")]
+impl<'a> Iterator for SplitWhitespaceIndices<'a> {
+    type Item = (usize, &'a str);
+
+    #[inline]
+    fn next(&mut self) -> Option<(usize, &'a str)> {
+        self.inner.next().map(|token| self.with_offset(token))
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.inner.size_hint()
+    }
+
+    #[inline]
+    fn last(mut self) -> Option<(usize, &'a str)> {
+        self.next_back()
+    }
+}
+
+#[unstable(feature = "str_split_whitespace_indices", issue = "none")]
+impl<'a> DoubleEndedIterator for SplitWhitespaceIndices<'a> {
+    #[inline]
+    fn next_back(&mut self) -> Option<(usize, &'a str)> {
+        self.inner.next_back().map(|token| self.with_offset(token))
+    }
+}
+
+#[unstable(feature = "str_split_whitespace_indices", issue = "none")]
+impl FusedIterator for SplitWhitespaceIndices<'_> {}
+
 #[stable(feature = "split_inclusive", since = "1.51.0")]
 impl<'a, P: Pattern<'a>> Iterator for SplitInclusive<'a, P> {
     type Item = &'a str;
@@ -1327,6 +2526,14 @@ impl<'a, P: Pattern<'a>> Iterator for SplitInclusive<'a, P> {
     fn next(&mut self) -> Option<&'a str> {
         self.0.next_inclusive()
     }
+
+    #[inline]
+    fn position<F>(&mut self, predicate: F) -> Option<usize>
+    where
+        F: FnMut(&'a str) -> bool,
+    {
+        self.0.position_inclusive(predicate)
+    }
 }
 
 #[stable(feature = "split_inclusive", since = "1.51.0")]
@@ -1352,6 +2559,15 @@ impl<'a, P: Pattern<'a, Searcher: ReverseSearcher<'a>>> DoubleEndedIterator
     fn next_back(&mut self) -> Option<&'a str> {
         self.0.next_back_inclusive()
     }
+
+    #[inline]
+    fn try_rfold<B, F, R>(&mut self, init: B, f: F) -> R
+    where
+        F: FnMut(B, &'a str) -> R,
+        R: Try<Output = B>,
+    {
+        self.0.try_rfold_inclusive(init, f)
+    }
 }
 
 #[stable(feature = "split_inclusive", since = "1.51.0")]
@@ -1378,117 +2594,842 @@ pub fn as_str(&self) -> &'a str {
     }
 }
 
-/// An iterator of [`u16`] over the string encoded as UTF-16.
+/// An iterator over substrings of a string, each terminated by a run of
+/// consecutive matches of a pattern.
 ///
-/// This struct is created by the [`encode_utf16`] method on [`str`].
-/// See its documentation for more.
+/// This struct is created by the [`split_inclusive_coalesced`] method on
+/// [`str`]. See its documentation for more.
 ///
-/// [`encode_utf16`]: str::encode_utf16
-#[derive(Clone)]
-#[stable(feature = "encode_utf16", since = "1.8.0")]
-pub struct EncodeUtf16<'a> {
-    pub(super) chars: Chars<'a>,
-    pub(super) extra: u16,
-}
-
-#[stable(feature = "collection_debug", since = "1.17.0")]
-impl fmt::Debug for EncodeUtf16<'_> {
-    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        f.debug_struct("EncodeUtf16").finish_non_exhaustive()
-    }
+/// [`split_inclusive_coalesced`]: str::split_inclusive_coalesced
+#[unstable(feature = "str_split_inclusive_coalesced", issue = "none")]
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct SplitInclusiveCoalesced<'a, P: Pattern<'a>> {
+    pub(super) start: usize,
+    pub(super) matcher: P::Searcher,
+    /// The first match of the next run, if it was already found while
+    /// looking for the end of the previous one
+    pub(super) pending: Option<(usize, usize)>,
+    pub(super) finished: bool,
 }
 
-#[stable(feature = "encode_utf16", since = "1.8.0")]
-impl<'a> Iterator for EncodeUtf16<'a> {
-    type Item = u16;
+#[unstable(feature = "str_split_inclusive_coalesced", issue = "none")]
+impl<'a, P: Pattern<'a>> Iterator for SplitInclusiveCoalesced<'a, P> {
+    type Item = &'a str;
 
     #[inline]
-    fn next(&mut self) -> Option<u16> {
-        if self.extra != 0 {
-            let tmp = self.extra;
-            self.extra = 0;
-            return Some(tmp);
+    fn next(&mut self) -> Option<&'a str> {
+        if self.finished {
+            return None;
         }
 
-        let mut buf = [0; 2];
-        self.chars.next().map(|ch| {
-            let n = ch.encode_utf16(&mut buf).len();
-            if n == 2 {
-                self.extra = buf[1];
+        let haystack = self.matcher.haystack();
+        loop {
+            let mut end = match self.pending.take().or_else(|| self.matcher.next_match()) {
+                Some((_, b)) => b,
+                None => {
+                    self.finished = true;
+                    if self.start == haystack.len() {
+                        return None;
+                    }
+                    // SAFETY: `self.start` is 0 or the end of a match, so it lies
+                    // on a unicode boundary.
+                    return Some(unsafe { haystack.get_unchecked(self.start..) });
+                }
+            };
+
+            // Extend the run over every match that starts where it ends.
+            while let Some((a, b)) = self.matcher.next_match() {
+                if a == end {
+                    end = b;
+                } else {
+                    self.pending = Some((a, b));
+                    break;
+                }
             }
-            buf[0]
-        })
+
+            // Only empty matches can end a run at the start of the piece.
+            if end == self.start {
+                continue;
+            }
+
+            // SAFETY: `Searcher` guarantees that `end` lies on a unicode boundary,
+            // and `self.start` is 0 or the end of a previous match.
+            let piece = unsafe { haystack.get_unchecked(self.start..end) };
+            self.start = end;
+            return Some(piece);
+        }
     }
+}
 
-    #[inline]
-    fn size_hint(&self) -> (usize, Option<usize>) {
-        let (low, high) = self.chars.size_hint();
-        // every char gets either one u16 or two u16,
-        // so this iterator is between 1 or 2 times as
-        // long as the underlying iterator.
-        (low, high.and_then(|n| n.checked_mul(2)))
+#[unstable(feature = "str_split_inclusive_coalesced", issue = "none")]
+impl<'a, P: Pattern<'a>> FusedIterator for SplitInclusiveCoalesced<'a, P> {}
+
+#[unstable(feature = "str_split_inclusive_coalesced", issue = "none")]
+impl<'a, P: Pattern<'a, Searcher: fmt::Debug>> fmt::Debug for SplitInclusiveCoalesced<'a, P> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("SplitInclusiveCoalesced")
+            .field("start", &self.start)
+            .field("matcher", &self.matcher)
+            .field("pending", &self.pending)
+            .field("finished", &self.finished)
+            .finish()
     }
 }
 
-#[stable(feature = "fused", since = "1.26.0")]
-impl FusedIterator for EncodeUtf16<'_> {}
+#[unstable(feature = "str_split_inclusive_coalesced", issue = "none")]
+impl<'a, P: Pattern<'a, Searcher: Clone>> Clone for SplitInclusiveCoalesced<'a, P> {
+    fn clone(&self) -> Self {
+        SplitInclusiveCoalesced {
+            start: self.start,
+            matcher: self.matcher.clone(),
+            pending: self.pending,
+            finished: self.finished,
+        }
+    }
+}
 
-/// The return type of [`str::escape_debug`].
-#[stable(feature = "str_escape", since = "1.34.0")]
+/// An iterator over substrings of a string, separated by any of a set of
+/// chars, each paired with the char that ended it.
+///
+/// This struct is created by the [`split_on_any_indexed`] method on [`str`].
+/// See its documentation for more.
+///
+/// [`split_on_any_indexed`]: str::split_on_any_indexed
 #[derive(Clone, Debug)]
-pub struct EscapeDebug<'a> {
-    pub(super) inner: Chain<
-        Flatten<option::IntoIter<char::EscapeDebug>>,
-        FlatMap<Chars<'a>, char::EscapeDebug, CharEscapeDebugContinue>,
-    >,
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+#[unstable(feature = "str_split_on_any_indexed", issue = "none")]
+pub struct SplitOnAnyIndexed<'a, 'b> {
+    pub(super) inner: Split<'a, &'b [char]>,
 }
 
-/// The return type of [`str::escape_default`].
-#[stable(feature = "str_escape", since = "1.34.0")]
-#[derive(Clone, Debug)]
-pub struct EscapeDefault<'a> {
-    pub(super) inner: FlatMap<Chars<'a>, char::EscapeDefault, CharEscapeDefault>,
+impl<'a, 'b> SplitOnAnyIndexed<'a, 'b> {
+    /// Pairs `piece` with the char that follows it in the haystack, which is
+    /// the separator that ended it unless it is the last piece.
+    #[inline]
+    fn with_separator(&self, piece: &'a str) -> (&'a str, Option<char>) {
+        let haystack = self.inner.0.matcher.haystack();
+        let end = piece.as_ptr() as usize - haystack.as_ptr() as usize + piece.len();
+        (piece, haystack[end..].chars().next())
+    }
 }
 
-/// The return type of [`str::escape_unicode`].
-#[stable(feature = "str_escape", since = "1.34.0")]
+#[unstable(feature = "str_split_on_any_indexed", issue = "none")]
+impl<'a, 'b> Iterator for SplitOnAnyIndexed<'a, 'b> {
+    type Item = (&'a str, Option<char>);
+
+    #[inline]
+    fn next(&mut self) -> Option<(&'a str, Option<char>)> {
+        let piece = self.inner.next()?;
+        Some(self.with_separator(piece))
+    }
+}
+
+#[unstable(feature = "str_split_on_any_indexed", issue = "none")]
+impl<'a, 'b> DoubleEndedIterator for SplitOnAnyIndexed<'a, 'b> {
+    #[inline]
+    fn next_back(&mut self) -> Option<(&'a str, Option<char>)> {
+        let piece = self.inner.next_back()?;
+        Some(self.with_separator(piece))
+    }
+}
+
+#[unstable(feature = "str_split_on_any_indexed", issue = "none")]
+impl FusedIterator for SplitOnAnyIndexed<'_, '_> {}
+
+/// An iterator over the matches of any of a set of chars within a string,
+/// as the byte index and the char that matched.
+///
+/// This struct is created by the [`match_indices_char_set`] method on
+/// [`str`]. See its documentation for more.
+///
+/// [`match_indices_char_set`]: str::match_indices_char_set
 #[derive(Clone, Debug)]
-pub struct EscapeUnicode<'a> {
-    pub(super) inner: FlatMap<Chars<'a>, char::EscapeUnicode, CharEscapeUnicode>,
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+#[unstable(feature = "str_char_set", issue = "none")]
+pub struct MatchIndicesCharSet<'a, 'b> {
+    pub(super) inner: MatchIndices<'a, &'b [char]>,
 }
 
-macro_rules! escape_types_impls {
-    ($( $Name: ident ),+) => {$(
-        #[stable(feature = "str_escape", since = "1.34.0")]
-        impl<'a> fmt::Display for $Name<'a> {
-            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-                self.clone().try_for_each(|c| f.write_char(c))
-            }
-        }
+#[unstable(feature = "str_char_set", issue = "none")]
+impl<'a, 'b> Iterator for MatchIndicesCharSet<'a, 'b> {
+    type Item = (usize, char);
 
-        #[stable(feature = "str_escape", since = "1.34.0")]
-        impl<'a> Iterator for $Name<'a> {
-            type Item = char;
+    #[inline]
+    fn next(&mut self) -> Option<(usize, char)> {
+        // Each match of a char set is exactly the one char that matched, so
+        // it is never empty.
+        self.inner.next().map(|(index, matched)| (index, matched.chars().next().unwrap()))
+    }
 
-            #[inline]
-            fn next(&mut self) -> Option<char> { self.inner.next() }
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.inner.size_hint()
+    }
+}
 
-            #[inline]
-            fn size_hint(&self) -> (usize, Option<usize>) { self.inner.size_hint() }
+#[unstable(feature = "str_char_set", issue = "none")]
+impl<'a, 'b> DoubleEndedIterator for MatchIndicesCharSet<'a, 'b> {
+    #[inline]
+    fn next_back(&mut self) -> Option<(usize, char)> {
+        self.inner.next_back().map(|(index, matched)| (index, matched.chars().next().unwrap()))
+    }
+}
 
-            #[inline]
-            fn try_fold<Acc, Fold, R>(&mut self, init: Acc, fold: Fold) -> R where
-                Self: Sized, Fold: FnMut(Acc, Self::Item) -> R, R: Try<Output = Acc>
-            {
-                self.inner.try_fold(init, fold)
-            }
+#[unstable(feature = "str_char_set", issue = "none")]
+impl FusedIterator for MatchIndicesCharSet<'_, '_> {}
 
-            #[inline]
+/// An iterator over substrings of a string, separated by a pattern, each
+/// paired with the separator that follows it.
+///
+/// This struct is created by the [`split_with_separators`] method on [`str`].
+/// See its documentation for more.
+///
+/// [`split_with_separators`]: str::split_with_separators
+#[unstable(feature = "str_split_with_separators", issue = "none")]
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct SplitWithSeparators<'a, P: Pattern<'a>> {
+    pub(super) start: usize,
+    pub(super) matcher: P::Searcher,
+    pub(super) finished: bool,
+}
+
+#[unstable(feature = "str_split_with_separators", issue = "none")]
+impl<'a, P: Pattern<'a>> Iterator for SplitWithSeparators<'a, P> {
+    type Item = (&'a str, Option<&'a str>);
+
+    #[inline]
+    fn next(&mut self) -> Option<(&'a str, Option<&'a str>)> {
+        if self.finished {
+            return None;
+        }
+
+        let haystack = self.matcher.haystack();
+        match self.matcher.next_match() {
+            // SAFETY: `Searcher` guarantees that `a` and `b` lie on unicode boundaries,
+            // and `self.start` is 0 or the end of a previous match.
+            Some((a, b)) => unsafe {
+                let piece = haystack.get_unchecked(self.start..a);
+                self.start = b;
+                Some((piece, Some(haystack.get_unchecked(a..b))))
+            },
+            None => {
+                self.finished = true;
+                // SAFETY: `self.start` is 0 or the end of a match.
+                Some((unsafe { haystack.get_unchecked(self.start..) }, None))
+            }
+        }
+    }
+}
+
+#[unstable(feature = "str_split_with_separators", issue = "none")]
+impl<'a, P: Pattern<'a>> FusedIterator for SplitWithSeparators<'a, P> {}
+
+#[unstable(feature = "str_split_with_separators", issue = "none")]
+impl<'a, P: Pattern<'a, Searcher: fmt::Debug>> fmt::Debug for SplitWithSeparators<'a, P> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("SplitWithSeparators")
+            .field("start", &self.start)
+            .field("matcher", &self.matcher)
+            .field("finished", &self.finished)
+            .finish()
+    }
+}
+
+#[unstable(feature = "str_split_with_separators", issue = "none")]
+impl<'a, P: Pattern<'a, Searcher: Clone>> Clone for SplitWithSeparators<'a, P> {
+    fn clone(&self) -> Self {
+        SplitWithSeparators {
+            start: self.start,
+            matcher: self.matcher.clone(),
+            finished: self.finished,
+        }
+    }
+}
+
+/// An iterator over substrings of a string, split between each pair of
+/// adjacent characters accepted by a closure.
+///
+/// This struct is created by the [`split_at_boundaries`] method on [`str`].
+/// See its documentation for more.
+///
+/// [`split_at_boundaries`]: str::split_at_boundaries
+#[unstable(feature = "str_split_at_boundaries", issue = "none")]
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+#[derive(Clone)]
+pub struct SplitAtBoundaries<'a, F> {
+    pub(super) remainder: &'a str,
+    pub(super) f: F,
+}
+
+#[unstable(feature = "str_split_at_boundaries", issue = "none")]
+impl<'a, F: FnMut(char, char) -> bool> Iterator for SplitAtBoundaries<'a, F> {
+    type Item = &'a str;
+
+    #[inline]
+    fn next(&mut self) -> Option<&'a str> {
+        let mut chars = self.remainder.char_indices();
+        let (_, mut prev) = chars.next()?;
+        for (i, c) in chars {
+            if (self.f)(prev, c) {
+                let (piece, rest) = self.remainder.split_at(i);
+                self.remainder = rest;
+                return Some(piece);
+            }
+            prev = c;
+        }
+        Some(mem::take(&mut self.remainder))
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        // Every piece holds at least one char, and each char holds at least
+        // one byte.
+        if self.remainder.is_empty() { (0, Some(0)) } else { (1, Some(self.remainder.len())) }
+    }
+}
+
+#[unstable(feature = "str_split_at_boundaries", issue = "none")]
+impl<'a, F: FnMut(char, char) -> bool> FusedIterator for SplitAtBoundaries<'a, F> {}
+
+#[unstable(feature = "str_split_at_boundaries", issue = "none")]
+impl<'a, F> fmt::Debug for SplitAtBoundaries<'a, F> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("SplitAtBoundaries").field("remainder", &self.remainder).finish()
+    }
+}
+
+/// An iterator over substrings of a string, separated by a pattern,
+/// with leading and trailing whitespace removed from each substring.
+///
+/// This struct is created by the [`split_trimmed`] method on [`str`].
+/// See its documentation for more.
+///
+/// [`split_trimmed`]: str::split_trimmed
+#[unstable(feature = "str_split_trimmed", issue = "none")]
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct SplitTrimmed<'a, P: Pattern<'a>> {
+    pub(super) inner: Map<Split<'a, P>, TrimWhitespace>,
+}
+
+#[unstable(feature = "str_split_trimmed", issue = "none")]
+impl<'a, P: Pattern<'a>> Iterator for SplitTrimmed<'a, P> {
+    type Item = &'a str;
+
+    #[inline]
+    fn next(&mut self) -> Option<&'a str> {
+        self.inner.next()
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.inner.size_hint()
+    }
+}
+
+#[unstable(feature = "str_split_trimmed", issue = "none")]
+impl<'a, P: Pattern<'a, Searcher: DoubleEndedSearcher<'a>>> DoubleEndedIterator
+    for SplitTrimmed<'a, P>
+{
+    #[inline]
+    fn next_back(&mut self) -> Option<&'a str> {
+        self.inner.next_back()
+    }
+}
+
+#[unstable(feature = "str_split_trimmed", issue = "none")]
+impl<'a, P: Pattern<'a>> FusedIterator for SplitTrimmed<'a, P> {}
+
+#[unstable(feature = "str_split_trimmed", issue = "none")]
+impl<'a, P: Pattern<'a, Searcher: fmt::Debug>> fmt::Debug for SplitTrimmed<'a, P> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("SplitTrimmed").field("inner", &self.inner).finish()
+    }
+}
+
+#[unstable(feature = "str_split_trimmed", issue = "none")]
+impl<'a, P: Pattern<'a, Searcher: Clone>> Clone for SplitTrimmed<'a, P> {
+    fn clone(&self) -> Self {
+        SplitTrimmed { inner: self.inner.clone() }
+    }
+}
+
+/// An iterator over adjacent pairs of substrings of a string, separated by
+/// a pattern.
+///
+/// This struct is created by the [`pairs`] method on [`Split`].
+/// See its documentation for more.
+///
+/// [`pairs`]: Split::pairs
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+#[unstable(feature = "str_split_pairs", issue = "none")]
+pub struct SplitPairs<'a, P: Pattern<'a>> {
+    pub(super) inner: Split<'a, P>,
+    /// The second piece of the last yielded pair
+    pub(super) prev: Option<&'a str>,
+}
+
+#[unstable(feature = "str_split_pairs", issue = "none")]
+impl<'a, P: Pattern<'a>> Iterator for SplitPairs<'a, P> {
+    type Item = (&'a str, &'a str);
+
+    #[inline]
+    fn next(&mut self) -> Option<(&'a str, &'a str)> {
+        let prev = match self.prev {
+            Some(prev) => prev,
+            None => self.inner.next()?,
+        };
+        let next = self.inner.next()?;
+        self.prev = Some(next);
+        Some((prev, next))
+    }
+}
+
+#[unstable(feature = "str_split_pairs", issue = "none")]
+impl<'a, P: Pattern<'a>> FusedIterator for SplitPairs<'a, P> {}
+
+#[unstable(feature = "str_split_pairs", issue = "none")]
+impl<'a, P: Pattern<'a, Searcher: fmt::Debug>> fmt::Debug for SplitPairs<'a, P> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("SplitPairs").field("inner", &self.inner).field("prev", &self.prev).finish()
+    }
+}
+
+#[unstable(feature = "str_split_pairs", issue = "none")]
+impl<'a, P: Pattern<'a, Searcher: Clone>> Clone for SplitPairs<'a, P> {
+    fn clone(&self) -> Self {
+        SplitPairs { inner: self.inner.clone(), prev: self.prev }
+    }
+}
+
+/// An iterator over the pieces of a [`Split`] together with their starting
+/// byte offsets in the original string.
+///
+/// This struct is created by the [`with_offsets`] method on [`Split`].
+/// See its documentation for more.
+///
+/// [`with_offsets`]: Split::with_offsets
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+#[unstable(feature = "str_split_with_offsets", issue = "none")]
+pub struct SplitWithOffsets<'a, P: Pattern<'a>> {
+    pub(super) inner: Split<'a, P>,
+}
+
+impl<'a, P: Pattern<'a>> SplitWithOffsets<'a, P> {
+    #[inline]
+    fn with_offset(&self, piece: &'a str) -> (usize, &'a str) {
+        // Every piece is a sub-slice of the haystack, so the difference between
+        // the two start pointers is the piece's byte offset.
+        let haystack = self.inner.0.matcher.haystack();
+        (piece.as_ptr() as usize - haystack.as_ptr() as usize, piece)
+    }
+}
+
+#[unstable(feature = "str_split_with_offsets", issue = "none")]
+impl<'a, P: Pattern<'a>> Iterator for SplitWithOffsets<'a, P> {
+    type Item = (usize, &'a str);
+
+    #[inline]
+    fn next(&mut self) -> Option<(usize, &'a str)> {
+        let piece = self.inner.next()?;
+        Some(self.with_offset(piece))
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.inner.size_hint()
+    }
+}
+
+#[unstable(feature = "str_split_with_offsets", issue = "none")]
+impl<'a, P> DoubleEndedIterator for SplitWithOffsets<'a, P>
+where
+    P: Pattern<'a, Searcher: DoubleEndedSearcher<'a>>,
+{
+    #[inline]
+    fn next_back(&mut self) -> Option<(usize, &'a str)> {
+        let piece = self.inner.next_back()?;
+        Some(self.with_offset(piece))
+    }
+}
+
+#[unstable(feature = "str_split_with_offsets", issue = "none")]
+impl<'a, P: Pattern<'a>> FusedIterator for SplitWithOffsets<'a, P> {}
+
+#[unstable(feature = "str_split_with_offsets", issue = "none")]
+impl<'a, P: Pattern<'a, Searcher: fmt::Debug>> fmt::Debug for SplitWithOffsets<'a, P> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("SplitWithOffsets").field("inner", &self.inner).finish()
+    }
+}
+
+#[unstable(feature = "str_split_with_offsets", issue = "none")]
+impl<'a, P: Pattern<'a, Searcher: Clone>> Clone for SplitWithOffsets<'a, P> {
+    fn clone(&self) -> Self {
+        SplitWithOffsets { inner: self.inner.clone() }
+    }
+}
+
+/// An iterator over the matches of a pattern within a string, together with
+/// a window of surrounding context.
+///
+/// This struct is created by the [`matches_context`] method on [`str`].
+/// See its documentation for more.
+///
+/// [`matches_context`]: str::matches_context
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+#[unstable(feature = "str_matches_context", issue = "none")]
+pub struct MatchesContext<'a, P: Pattern<'a>> {
+    pub(super) haystack: &'a str,
+    pub(super) inner: MatchIndices<'a, P>,
+    pub(super) before: usize,
+    pub(super) after: usize,
+}
+
+impl<'a, P: Pattern<'a>> MatchesContext<'a, P> {
+    /// Extends the match at `idx` by up to `before` characters to the left
+    /// and `after` characters to the right.
+    #[inline]
+    fn with_context(&self, idx: usize, matched: &'a str) -> (&'a str, &'a str) {
+        let haystack = self.haystack;
+        let end = idx + matched.len();
+        let context_start =
+            haystack[..idx].char_indices().rev().take(self.before).last().map_or(idx, |(i, _)| i);
+        let context_end =
+            haystack[end..].char_indices().nth(self.after).map_or(haystack.len(), |(i, _)| end + i);
+        // SAFETY: both bounds come from `char_indices` on subslices split at
+        // the match boundaries, so they lie on char boundaries of `haystack`.
+        (matched, unsafe { haystack.get_unchecked(context_start..context_end) })
+    }
+}
+
+#[unstable(feature = "str_matches_context", issue = "none")]
+impl<'a, P: Pattern<'a>> Iterator for MatchesContext<'a, P> {
+    type Item = (&'a str, &'a str);
+
+    #[inline]
+    fn next(&mut self) -> Option<(&'a str, &'a str)> {
+        let (idx, matched) = self.inner.next()?;
+        Some(self.with_context(idx, matched))
+    }
+}
+
+#[unstable(feature = "str_matches_context", issue = "none")]
+impl<'a, P> DoubleEndedIterator for MatchesContext<'a, P>
+where
+    P: Pattern<'a, Searcher: DoubleEndedSearcher<'a>>,
+{
+    #[inline]
+    fn next_back(&mut self) -> Option<(&'a str, &'a str)> {
+        let (idx, matched) = self.inner.next_back()?;
+        Some(self.with_context(idx, matched))
+    }
+}
+
+#[unstable(feature = "str_matches_context", issue = "none")]
+impl<'a, P: Pattern<'a>> FusedIterator for MatchesContext<'a, P> {}
+
+#[unstable(feature = "str_matches_context", issue = "none")]
+impl<'a, P: Pattern<'a, Searcher: fmt::Debug>> fmt::Debug for MatchesContext<'a, P> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("MatchesContext")
+            .field("inner", &self.inner)
+            .field("before", &self.before)
+            .field("after", &self.after)
+            .finish()
+    }
+}
+
+#[unstable(feature = "str_matches_context", issue = "none")]
+impl<'a, P: Pattern<'a, Searcher: Clone>> Clone for MatchesContext<'a, P> {
+    fn clone(&self) -> Self {
+        MatchesContext {
+            haystack: self.haystack,
+            inner: self.inner.clone(),
+            before: self.before,
+            after: self.after,
+        }
+    }
+}
+
+/// A piece of a string yielded by [`MatchesAndGaps`].
+///
+/// See [`str::matches_and_gaps`] for more.
+#[unstable(feature = "str_matches_and_gaps", issue = "none")]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum MatchOrGap<'a> {
+    /// A substring matched by the pattern.
+    Match(&'a str),
+    /// A non-empty substring between two matches, or between a match and
+    /// either end of the string.
+    Gap(&'a str),
+}
+
+impl<'a> MatchOrGap<'a> {
+    /// Returns the string slice of this piece, whether it is a match or a gap.
+    #[unstable(feature = "str_matches_and_gaps", issue = "none")]
+    #[must_use]
+    #[inline]
+    pub fn as_str(&self) -> &'a str {
+        match *self {
+            MatchOrGap::Match(s) | MatchOrGap::Gap(s) => s,
+        }
+    }
+}
+
+/// An iterator over the matches of a pattern within a string, and the
+/// substrings between them.
+///
+/// This struct is created by the [`matches_and_gaps`] method on [`str`].
+/// See its documentation for more.
+///
+/// [`matches_and_gaps`]: str::matches_and_gaps
+#[unstable(feature = "str_matches_and_gaps", issue = "none")]
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct MatchesAndGaps<'a, P: Pattern<'a>> {
+    pub(super) haystack: &'a str,
+    pub(super) searcher: P::Searcher,
+    /// The end of the last yielded piece
+    pub(super) position: usize,
+    /// A match that was found after a gap, and is yielded after it
+    pub(super) pending: Option<(usize, usize)>,
+    pub(super) finished: bool,
+}
+
+#[unstable(feature = "str_matches_and_gaps", issue = "none")]
+impl<'a, P: Pattern<'a>> Iterator for MatchesAndGaps<'a, P> {
+    type Item = MatchOrGap<'a>;
+
+    #[inline]
+    fn next(&mut self) -> Option<MatchOrGap<'a>> {
+        let haystack = self.haystack;
+        if let Some((a, b)) = self.pending.take() {
+            self.position = b;
+            // SAFETY: `Searcher` guarantees that `a` and `b` lie on unicode boundaries.
+            return Some(MatchOrGap::Match(unsafe { haystack.get_unchecked(a..b) }));
+        }
+        if self.finished {
+            return None;
+        }
+
+        match self.searcher.next_match() {
+            Some((a, b)) if a > self.position => {
+                // SAFETY: `position` is the end of the previous match (or 0),
+                // and `a` is the start of this one, so both are on unicode
+                // boundaries.
+                let gap = unsafe { haystack.get_unchecked(self.position..a) };
+                self.pending = Some((a, b));
+                self.position = a;
+                Some(MatchOrGap::Gap(gap))
+            }
+            Some((a, b)) => {
+                self.position = b;
+                // SAFETY: `Searcher` guarantees that `a` and `b` lie on unicode boundaries.
+                Some(MatchOrGap::Match(unsafe { haystack.get_unchecked(a..b) }))
+            }
+            None => {
+                self.finished = true;
+                if self.position == haystack.len() {
+                    return None;
+                }
+                // SAFETY: `position` is the end of the previous match (or 0).
+                let gap = unsafe { haystack.get_unchecked(self.position..) };
+                self.position = haystack.len();
+                Some(MatchOrGap::Gap(gap))
+            }
+        }
+    }
+}
+
+#[unstable(feature = "str_matches_and_gaps", issue = "none")]
+impl<'a, P: Pattern<'a>> FusedIterator for MatchesAndGaps<'a, P> {}
+
+#[unstable(feature = "str_matches_and_gaps", issue = "none")]
+impl<'a, P: Pattern<'a, Searcher: fmt::Debug>> fmt::Debug for MatchesAndGaps<'a, P> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("MatchesAndGaps")
+            .field("searcher", &self.searcher)
+            .field("position", &self.position)
+            .field("pending", &self.pending)
+            .field("finished", &self.finished)
+            .finish()
+    }
+}
+
+#[unstable(feature = "str_matches_and_gaps", issue = "none")]
+impl<'a, P: Pattern<'a, Searcher: Clone>> Clone for MatchesAndGaps<'a, P> {
+    fn clone(&self) -> Self {
+        MatchesAndGaps {
+            haystack: self.haystack,
+            searcher: self.searcher.clone(),
+            position: self.position,
+            pending: self.pending,
+            finished: self.finished,
+        }
+    }
+}
+
+/// An iterator of [`u16`] over the string encoded as UTF-16.
+///
+/// This struct is created by the [`encode_utf16`] method on [`str`].
+/// See its documentation for more.
+///
+/// [`encode_utf16`]: str::encode_utf16
+#[derive(Clone)]
+#[stable(feature = "encode_utf16", since = "1.8.0")]
+pub struct EncodeUtf16<'a> {
+    pub(super) chars: Chars<'a>,
+    pub(super) extra: u16,
+}
+
+#[stable(feature = "collection_debug", since = "1.17.0")]
+impl fmt::Debug for EncodeUtf16<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("EncodeUtf16").finish_non_exhaustive()
+    }
+}
+
+#[stable(feature = "encode_utf16", since = "1.8.0")]
+impl<'a> Iterator for EncodeUtf16<'a> {
+    type Item = u16;
+
+    #[inline]
+    fn next(&mut self) -> Option<u16> {
+        if self.extra != 0 {
+            let tmp = self.extra;
+            self.extra = 0;
+            return Some(tmp);
+        }
+
+        let mut buf = [0; 2];
+        self.chars.next().map(|ch| {
+            let n = ch.encode_utf16(&mut buf).len();
+            if n == 2 {
+                self.extra = buf[1];
+            }
+            buf[0]
+        })
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let (low, high) = self.chars.size_hint();
+        // every char gets either one u16 or two u16,
+        // so this iterator is between 1 or 2 times as
+        // long as the underlying iterator.
+        (low, high.and_then(|n| n.checked_mul(2)))
+    }
+}
+
+#[stable(feature = "fused", since = "1.26.0")]
+impl FusedIterator for EncodeUtf16<'_> {}
+
+impl<'a> EncodeUtf16<'a> {
+    /// Writes the remaining UTF-16 code units into `buf`.
+    ///
+    /// Returns `Ok` with the number of code units written if they all fit.
+    /// Otherwise, returns `Err` with the number of code units `buf` would
+    /// need to hold them all. In that case `buf` starts with as many
+    /// code units as fit without splitting a surrogate pair; the rest of
+    /// `buf` is left unchanged.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(encode_utf16_write_to)]
+    ///
+    /// let mut buf = [0; 8];
+    /// assert_eq!("a𝄞b".encode_utf16().write_to(&mut buf), Ok(4));
+    /// assert_eq!(buf[..4], [0x61, 0xD834, 0xDD1E, 0x62]);
+    ///
+    /// // The surrogate pair for '𝄞' does not fit after 'a',
+    /// // so only 'a' is written.
+    /// let mut buf = [0; 2];
+    /// assert_eq!("a𝄞b".encode_utf16().write_to(&mut buf), Err(4));
+    /// assert_eq!(buf, [0x61, 0]);
+    /// ```
+    #[unstable(feature = "encode_utf16_write_to", issue = "none")]
+    pub fn write_to(mut self, buf: &mut [u16]) -> Result<usize, usize> {
+        let mut written = 0;
+        if self.extra != 0 {
+            // The leading surrogate has already been yielded, so this
+            // completes a pair rather than starting one.
+            match buf.first_mut() {
+                Some(unit) => *unit = self.extra,
+                None => return Err(1 + self.chars.map(|ch| ch.len_utf16()).sum::<usize>()),
+            }
+            written = 1;
+        }
+
+        while let Some(ch) = self.chars.next() {
+            let len = ch.len_utf16();
+            if buf.len() - written < len {
+                return Err(written + len + self.chars.map(|ch| ch.len_utf16()).sum::<usize>());
+            }
+            ch.encode_utf16(&mut buf[written..]);
+            written += len;
+        }
+        Ok(written)
+    }
+}
+
+/// The return type of [`str::escape_debug`].
+#[stable(feature = "str_escape", since = "1.34.0")]
+#[derive(Clone, Debug)]
+pub struct EscapeDebug<'a> {
+    pub(super) inner: Chain<
+        Flatten<option::IntoIter<char::EscapeDebug>>,
+        FlatMap<Chars<'a>, char::EscapeDebug, CharEscapeDebugContinue>,
+    >,
+}
+
+/// The return type of [`str::escape_default`].
+#[stable(feature = "str_escape", since = "1.34.0")]
+#[derive(Clone, Debug)]
+pub struct EscapeDefault<'a> {
+    pub(super) inner: FlatMap<Chars<'a>, char::EscapeDefault, CharEscapeDefault>,
+}
+
+/// The return type of [`str::escape_unicode`].
+#[stable(feature = "str_escape", since = "1.34.0")]
+#[derive(Clone, Debug)]
+pub struct EscapeUnicode<'a> {
+    pub(super) inner: FlatMap<Chars<'a>, char::EscapeUnicode, CharEscapeUnicode>,
+}
+
+macro_rules! escape_types_impls {
+    ($( $Name: ident $({ $($extra: tt)* })? ),+) => {$(
+        #[stable(feature = "str_escape", since = "1.34.0")]
+        impl<'a> fmt::Display for $Name<'a> {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                self.clone().try_for_each(|c| f.write_char(c))
+            }
+        }
+
+        #[stable(feature = "str_escape", since = "1.34.0")]
+        impl<'a> Iterator for $Name<'a> {
+            type Item = char;
+
+            #[inline]
+            fn next(&mut self) -> Option<char> { self.inner.next() }
+
+            #[inline]
+            fn size_hint(&self) -> (usize, Option<usize>) { self.inner.size_hint() }
+
+            #[inline]
+            fn try_fold<Acc, Fold, R>(&mut self, init: Acc, fold: Fold) -> R where
+                Self: Sized, Fold: FnMut(Acc, Self::Item) -> R, R: Try<Output = Acc>
+            {
+                self.inner.try_fold(init, fold)
+            }
+
+            #[inline]
             fn fold<Acc, Fold>(self, init: Acc, fold: Fold) -> Acc
                 where Fold: FnMut(Acc, Self::Item) -> Acc,
             {
                 self.inner.fold(init, fold)
             }
+
+            $($($extra)*)?
         }
 
         #[stable(feature = "str_escape", since = "1.34.0")]
@@ -1496,4 +3437,138 @@ impl<'a> FusedIterator for $Name<'a> {}
     )+}
 }
 
-escape_types_impls!(EscapeDebug, EscapeDefault, EscapeUnicode);
+escape_types_impls!(
+    EscapeDebug,
+    EscapeDefault {
+        #[inline]
+        fn last(self) -> Option<char> {
+            // Every char escapes to at least one char, so the last escaped
+            // char is the end of the escape of the last char not yet started.
+            // Only that one char needs to be escaped.
+            let (front, mut rest, back) = self.inner.into_parts();
+            back.and_then(Iterator::last)
+                .or_else(|| rest.next_back().and_then(Iterator::last))
+                .or_else(|| front.and_then(Iterator::last))
+        }
+    },
+    EscapeUnicode
+);
+
+/// The return type of [`str::escape_ascii`].
+#[unstable(feature = "str_escape_ascii", issue = "none")]
+#[derive(Clone, Debug)]
+pub struct EscapeAscii<'a> {
+    pub(super) inner: slice::EscapeAscii<'a>,
+}
+
+#[unstable(feature = "str_escape_ascii", issue = "none")]
+impl<'a> fmt::Display for EscapeAscii<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(&self.inner, f)
+    }
+}
+
+#[unstable(feature = "str_escape_ascii", issue = "none")]
+impl<'a> Iterator for EscapeAscii<'a> {
+    type Item = char;
+
+    #[inline]
+    fn next(&mut self) -> Option<char> {
+        // The escaped output is always ASCII.
+        self.inner.next().map(|b| b as char)
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.inner.size_hint()
+    }
+
+    #[inline]
+    fn try_fold<Acc, Fold, R>(&mut self, init: Acc, mut fold: Fold) -> R
+    where
+        Self: Sized,
+        Fold: FnMut(Acc, Self::Item) -> R,
+        R: Try<Output = Acc>,
+    {
+        self.inner.try_fold(init, |acc, b| fold(acc, b as char))
+    }
+
+    #[inline]
+    fn fold<Acc, Fold>(self, init: Acc, mut fold: Fold) -> Acc
+    where
+        Fold: FnMut(Acc, Self::Item) -> Acc,
+    {
+        self.inner.fold(init, |acc, b| fold(acc, b as char))
+    }
+
+    #[inline]
+    fn count(self) -> usize {
+        self.inner.count()
+    }
+
+    #[inline]
+    fn last(self) -> Option<char> {
+        self.inner.last().map(|b| b as char)
+    }
+}
+
+#[unstable(feature = "str_escape_ascii", issue = "none")]
+impl<'a> DoubleEndedIterator for EscapeAscii<'a> {
+    #[inline]
+    fn next_back(&mut self) -> Option<char> {
+        self.inner.next_back().map(|b| b as char)
+    }
+}
+
+#[unstable(feature = "str_escape_ascii", issue = "none")]
+impl<'a> FusedIterator for EscapeAscii<'a> {}
+
+/// The return type of [`str::escape_control`].
+#[unstable(feature = "str_escape_control", issue = "none")]
+#[derive(Clone, Debug)]
+pub struct EscapeControl<'a> {
+    pub(super) inner: FlatMap<Chars<'a>, char::EscapeDebug, CharEscapeControl>,
+}
+
+#[unstable(feature = "str_escape_control", issue = "none")]
+impl<'a> fmt::Display for EscapeControl<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.clone().try_for_each(|c| f.write_char(c))
+    }
+}
+
+#[unstable(feature = "str_escape_control", issue = "none")]
+impl<'a> Iterator for EscapeControl<'a> {
+    type Item = char;
+
+    #[inline]
+    fn next(&mut self) -> Option<char> {
+        self.inner.next()
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.inner.size_hint()
+    }
+
+    #[inline]
+    fn try_fold<Acc, Fold, R>(&mut self, init: Acc, fold: Fold) -> R
+    where
+        Self: Sized,
+        Fold: FnMut(Acc, Self::Item) -> R,
+        R: Try<Output = Acc>,
+    {
+        self.inner.try_fold(init, fold)
+    }
+
+    #[inline]
+    fn fold<Acc, Fold>(self, init: Acc, fold: Fold) -> Acc
+    where
+        Fold: FnMut(Acc, Self::Item) -> Acc,
+    {
+        self.inner.fold(init, fold)
+    }
+}
+
+#[unstable(feature = "str_escape_control", issue = "none")]
+impl<'a> FusedIterator for EscapeControl<'a> {}
@@ -14,10 +14,11 @@
 mod validations;
 
 use self::pattern::Pattern;
-use self::pattern::{DoubleEndedSearcher, ReverseSearcher, Searcher};
+use self::pattern::{DoubleEndedSearcher, ReverseSearcher, SearchStep, Searcher};
 
 use crate::char::{self, EscapeDebugExtArgs};
 use crate::mem;
+use crate::ops::Range;
 use crate::slice::{self, SliceIndex};
 
 pub mod pattern;
@@ -32,6 +33,9 @@
 #[stable(feature = "str_mut_extras", since = "1.20.0")]
 pub use converts::{from_utf8_mut, from_utf8_unchecked_mut};
 
+#[unstable(feature = "str_valid_prefix_len", issue = "none")]
+pub use converts::valid_prefix_len;
+
 #[stable(feature = "rust1", since = "1.0.0")]
 pub use error::{ParseBoolError, Utf8Error};
 
@@ -45,6 +49,12 @@
 #[allow(deprecated)]
 pub use iter::LinesAny;
 
+#[unstable(feature = "str_lines_lf", issue = "none")]
+pub use iter::LinesLf;
+
+#[unstable(feature = "str_numbered_lines", issue = "none")]
+pub use iter::NumberedLines;
+
 #[stable(feature = "rust1", since = "1.0.0")]
 pub use iter::{RSplit, RSplitTerminator, Split, SplitTerminator};
 
@@ -54,26 +64,82 @@
 #[stable(feature = "str_matches", since = "1.2.0")]
 pub use iter::{Matches, RMatches};
 
+#[unstable(feature = "str_matches_max_items", issue = "none")]
+pub use iter::{MatchesN, RMatchesN};
+
 #[stable(feature = "str_match_indices", since = "1.5.0")]
 pub use iter::{MatchIndices, RMatchIndices};
 
+#[unstable(feature = "str_match_ranges", issue = "none")]
+pub use iter::{MatchRanges, RMatchRanges};
+
 #[stable(feature = "encode_utf16", since = "1.8.0")]
 pub use iter::EncodeUtf16;
 
 #[stable(feature = "str_escape", since = "1.34.0")]
 pub use iter::{EscapeDebug, EscapeDefault, EscapeUnicode};
 
+#[unstable(feature = "str_escape_ascii", issue = "none")]
+pub use iter::EscapeAscii;
+
+#[unstable(feature = "str_escape_control", issue = "none")]
+pub use iter::EscapeControl;
+
 #[stable(feature = "split_ascii_whitespace", since = "1.34.0")]
 pub use iter::SplitAsciiWhitespace;
 
 #[stable(feature = "split_inclusive", since = "1.51.0")]
 pub use iter::SplitInclusive;
 
+#[unstable(feature = "str_split_trimmed", issue = "none")]
+pub use iter::SplitTrimmed;
+
+#[unstable(feature = "str_split_whitespace_indices", issue = "none")]
+pub use iter::SplitWhitespaceIndices;
+
+#[unstable(feature = "str_split_byte_chunks", issue = "none")]
+pub use iter::SplitByteChunks;
+
+#[unstable(feature = "str_split_pairs", issue = "none")]
+pub use iter::SplitPairs;
+
+#[unstable(feature = "str_split_with_offsets", issue = "none")]
+pub use iter::SplitWithOffsets;
+
+#[unstable(feature = "str_matches_context", issue = "none")]
+pub use iter::MatchesContext;
+
+#[unstable(feature = "str_matches_and_gaps", issue = "none")]
+pub use iter::{MatchOrGap, MatchesAndGaps};
+
+#[unstable(feature = "str_split_inclusive_coalesced", issue = "none")]
+pub use iter::SplitInclusiveCoalesced;
+
+#[unstable(feature = "str_split_on_any_indexed", issue = "none")]
+pub use iter::SplitOnAnyIndexed;
+
+#[unstable(feature = "str_char_set", issue = "none")]
+pub use iter::MatchIndicesCharSet;
+
+#[unstable(feature = "str_split_with_separators", issue = "none")]
+pub use iter::SplitWithSeparators;
+
+#[unstable(feature = "str_split_at_boundaries", issue = "none")]
+pub use iter::SplitAtBoundaries;
+
+#[unstable(feature = "str_split_ranges", issue = "none")]
+pub use iter::{RSplitRanges, SplitRanges};
+
+#[unstable(feature = "str_char_boundary_indices", issue = "none")]
+pub use iter::CharBoundaryIndices;
+
 #[unstable(feature = "str_internals", issue = "none")]
 pub use validations::{next_code_point, utf8_char_width};
 
 use iter::MatchIndicesInternal;
+use iter::MatchRangesInternal;
 use iter::SplitInternal;
+use iter::SplitRangesInternal;
 use iter::{MatchesInternal, SplitNInternal};
 
 #[inline(never)]
@@ -840,6 +906,110 @@ pub fn char_indices(&self) -> CharIndices<'_> {
         CharIndices { front_offset: 0, iter: self.chars() }
     }
 
+    /// Returns an iterator over the char boundaries of a string slice.
+    ///
+    /// This yields the byte position of every char boundary in order, that
+    /// is, the start of each [`char`] followed by the length of the string.
+    /// Both `0` and `len()` are always included, so an empty string yields
+    /// just `0`.
+    ///
+    /// These are exactly the positions at which [`is_char_boundary`] returns
+    /// `true`, which makes this a cheap starting point for finding larger
+    /// boundaries such as grapheme clusters.
+    ///
+    /// [`char`]: prim@char
+    /// [`is_char_boundary`]: str::is_char_boundary
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(str_char_boundary_indices)]
+    /// let v: Vec<usize> = "aé".char_boundary_indices().collect();
+    /// assert_eq!(v, [0, 1, 3]);
+    ///
+    /// let v: Vec<usize> = "aé".char_boundary_indices().rev().collect();
+    /// assert_eq!(v, [3, 1, 0]);
+    ///
+    /// let v: Vec<usize> = "".char_boundary_indices().collect();
+    /// assert_eq!(v, [0]);
+    /// ```
+    #[unstable(feature = "str_char_boundary_indices", issue = "none")]
+    #[inline]
+    pub fn char_boundary_indices(&self) -> CharBoundaryIndices<'_> {
+        CharBoundaryIndices { inner: self.char_indices(), end: Some(self.len()) }
+    }
+
+    /// Returns the number of [`char`]s before byte index `byte`.
+    ///
+    /// Returns `None` if `byte` is not a char boundary, as determined by
+    /// [`is_char_boundary`]. The end of the string is a char boundary, so
+    /// `self.len()` maps to the total number of chars.
+    ///
+    /// This is the inverse of [`byte_of_char_index`]. Both take time linear in
+    /// the position, since chars have to be counted from the start.
+    ///
+    /// [`char`]: prim@char
+    /// [`is_char_boundary`]: str::is_char_boundary
+    /// [`byte_of_char_index`]: str::byte_of_char_index
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(str_char_index_of_byte)]
+    /// let s = "aé";
+    /// assert_eq!(s.char_index_of_byte(0), Some(0));
+    /// assert_eq!(s.char_index_of_byte(1), Some(1));
+    /// assert_eq!(s.char_index_of_byte(2), None); // inside 'é'
+    /// assert_eq!(s.char_index_of_byte(3), Some(2));
+    /// assert_eq!(s.char_index_of_byte(4), None);
+    /// ```
+    #[unstable(feature = "str_char_index_of_byte", issue = "none")]
+    #[must_use]
+    #[inline]
+    pub fn char_index_of_byte(&self, byte: usize) -> Option<usize> {
+        if !self.is_char_boundary(byte) {
+            return None;
+        }
+        // SAFETY: `byte` was just checked to be a char boundary.
+        Some(unsafe { self.get_unchecked(..byte) }.chars().count())
+    }
+
+    /// Returns the byte index of the `n`th [`char`], counting from zero.
+    ///
+    /// If `n` is the total number of chars, this returns `self.len()`, so
+    /// every result can be used to slice `self`. Returns `None` if `n` is
+    /// greater than that.
+    ///
+    /// This is the inverse of [`char_index_of_byte`].
+    ///
+    /// [`char`]: prim@char
+    /// [`char_index_of_byte`]: str::char_index_of_byte
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(str_char_index_of_byte)]
+    /// let s = "aé";
+    /// assert_eq!(s.byte_of_char_index(0), Some(0));
+    /// assert_eq!(s.byte_of_char_index(1), Some(1));
+    /// assert_eq!(s.byte_of_char_index(2), Some(3));
+    /// assert_eq!(s.byte_of_char_index(3), None);
+    /// ```
+    #[unstable(feature = "str_char_index_of_byte", issue = "none")]
+    #[must_use]
+    #[inline]
+    pub fn byte_of_char_index(&self, n: usize) -> Option<usize> {
+        if n == 0 {
+            return Some(0);
+        }
+        // `CharIndices::nth` skips by bytes without decoding. Stepping past
+        // the char before the `n`th one leaves the iterator at its start, or
+        // at the end of the string if there are exactly `n` chars.
+        let mut chars = self.char_indices();
+        chars.nth(n - 1)?;
+        Some(chars.offset())
+    }
+
     /// An iterator over the bytes of a string slice.
     ///
     /// As a string slice consists of a sequence of bytes, we can iterate
@@ -865,6 +1035,36 @@ pub fn bytes(&self) -> Bytes<'_> {
         Bytes(self.as_bytes().iter().copied())
     }
 
+    /// An iterator over string slices of at most `max_len` bytes each.
+    ///
+    /// Every chunk ends on a char boundary, so a chunk may be shorter than
+    /// `max_len` if the next char does not fit. A single char that is longer
+    /// than `max_len` is yielded as a chunk on its own.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `max_len` is 0.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(str_split_byte_chunks)]
+    ///
+    /// let v: Vec<&str> = "abcde".split_byte_chunks(2).collect();
+    /// assert_eq!(v, ["ab", "cd", "e"]);
+    ///
+    /// // '楽' is three bytes long, so it can neither share a chunk with 'a'
+    /// // nor fit in two bytes.
+    /// let v: Vec<&str> = "a楽b".split_byte_chunks(2).collect();
+    /// assert_eq!(v, ["a", "楽", "b"]);
+    /// ```
+    #[unstable(feature = "str_split_byte_chunks", issue = "none")]
+    #[inline]
+    pub fn split_byte_chunks(&self, max_len: usize) -> SplitByteChunks<'_> {
+        assert!(max_len != 0, "chunk size must be non-zero");
+        SplitByteChunks { v: self, max_len }
+    }
+
     /// Splits a string slice by whitespace.
     ///
     /// The iterator returned will return string slices that are sub-slices of
@@ -911,6 +1111,33 @@ pub fn split_whitespace(&self) -> SplitWhitespace<'_> {
         SplitWhitespace { inner: self.split(IsWhitespace).filter(IsNotEmpty) }
     }
 
+    /// Splits a string slice by whitespace, yielding each token together with
+    /// its starting byte offset in `self`.
+    ///
+    /// The tokens are exactly those returned by [`split_whitespace`], and
+    /// 'whitespace' has the same Unicode meaning.
+    ///
+    /// [`split_whitespace`]: str::split_whitespace
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(str_split_whitespace_indices)]
+    ///
+    /// let v: Vec<_> = "  foo bar".split_whitespace_indices().collect();
+    /// assert_eq!(v, [(2, "foo"), (6, "bar")]);
+    ///
+    /// let v: Vec<_> = "a\u{a0}b".split_whitespace_indices().collect();
+    /// assert_eq!(v, [(0, "a"), (3, "b")]);
+    /// ```
+    #[must_use = "this returns the split string as an iterator, \
+                  without modifying the original"]
+    #[unstable(feature = "str_split_whitespace_indices", issue = "none")]
+    #[inline]
+    pub fn split_whitespace_indices(&self) -> SplitWhitespaceIndices<'_> {
+        SplitWhitespaceIndices { haystack: self, inner: self.split_whitespace() }
+    }
+
     /// Splits a string slice by ASCII whitespace.
     ///
     /// The iterator returned will return string slices that are sub-slices of
@@ -956,6 +1183,45 @@ pub fn split_ascii_whitespace(&self) -> SplitAsciiWhitespace<'_> {
         SplitAsciiWhitespace { inner }
     }
 
+    /// Splits off the last whitespace-separated word of a string slice.
+    ///
+    /// Returns `(head, last)`, where `last` is the final non-whitespace
+    /// substring and `head` is everything before it. The whitespace around
+    /// `last` is consumed: `head` has its trailing whitespace removed, and any
+    /// whitespace after `last` is discarded. If the string is a single word,
+    /// `head` is empty.
+    ///
+    /// Returns `None` if the string is empty or consists only of whitespace.
+    ///
+    /// 'Whitespace' is defined according to the terms of the Unicode Derived
+    /// Core Property `White_Space`, as in [`split_whitespace`].
+    ///
+    /// [`split_whitespace`]: str::split_whitespace
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(str_rsplit_at_whitespace)]
+    ///
+    /// assert_eq!("a b   c  ".rsplit_at_whitespace(), Some(("a b", "c")));
+    /// assert_eq!(" word ".rsplit_at_whitespace(), Some(("", "word")));
+    /// assert_eq!(" \t\n".rsplit_at_whitespace(), None);
+    /// ```
+    #[must_use = "this returns the split string as a pair of slices, \
+                  without modifying the original"]
+    #[unstable(feature = "str_rsplit_at_whitespace", issue = "none")]
+    #[inline]
+    pub fn rsplit_at_whitespace(&self) -> Option<(&str, &str)> {
+        let trimmed = self.trim_end();
+        if trimmed.is_empty() {
+            return None;
+        }
+        match trimmed.rsplit_once(IsWhitespace) {
+            Some((head, last)) => Some((head.trim_end(), last)),
+            None => Some(("", trimmed)),
+        }
+    }
+
     /// An iterator over the lines of a string, as string slices.
     ///
     /// Lines are ended with either a newline (`\n`) or a carriage return with
@@ -1009,6 +1275,69 @@ pub fn lines_any(&self) -> LinesAny<'_> {
         LinesAny(self.lines())
     }
 
+    /// An iterator over the lines of a string, as string slices, where lines
+    /// are ended only by a newline (`\n`).
+    ///
+    /// Unlike [`lines`], a carriage return before the newline is not
+    /// stripped, so each line keeps any `\r` it ends with. This is cheaper
+    /// when the input is known to use `\n` line endings.
+    ///
+    /// As with [`lines`], the final line ending is optional.
+    ///
+    /// [`lines`]: str::lines
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(str_lines_lf)]
+    /// let text = "foo\r\nbar\n\nbaz\n";
+    /// let mut lines = text.lines_lf();
+    ///
+    /// assert_eq!(Some("foo\r"), lines.next());
+    /// assert_eq!(Some("bar"), lines.next());
+    /// assert_eq!(Some(""), lines.next());
+    /// assert_eq!(Some("baz"), lines.next());
+    ///
+    /// assert_eq!(None, lines.next());
+    /// ```
+    #[unstable(feature = "str_lines_lf", issue = "none")]
+    #[inline]
+    pub fn lines_lf(&self) -> LinesLf<'_> {
+        LinesLf(self.split_terminator('\n'))
+    }
+
+    /// An iterator over the lines of a string, as string slices, each paired
+    /// with its line number, counting from 1.
+    ///
+    /// Lines are split exactly as by [`lines`], and the numbers are those
+    /// that `lines().enumerate()` would give, plus one.
+    ///
+    /// The iterator can also be used from the back. The first line taken from
+    /// the back has to count the lines before it to learn its number, which
+    /// takes a pass over the rest of the string; later ones don't.
+    ///
+    /// [`lines`]: str::lines
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(str_numbered_lines)]
+    /// let text = "foo\r\nbar\n\nbaz\n";
+    /// let mut lines = text.numbered_lines();
+    ///
+    /// assert_eq!(Some((1, "foo")), lines.next());
+    /// assert_eq!(Some((4, "baz")), lines.next_back());
+    /// assert_eq!(Some((2, "bar")), lines.next());
+    /// assert_eq!(Some((3, "")), lines.next());
+    ///
+    /// assert_eq!(None, lines.next());
+    /// ```
+    #[unstable(feature = "str_numbered_lines", issue = "none")]
+    #[inline]
+    pub fn numbered_lines(&self) -> NumberedLines<'_> {
+        NumberedLines { inner: self.lines(), front: 1, back: None }
+    }
+
     /// Returns an iterator of `u16` over the string encoded as UTF-16.
     ///
     /// # Examples
@@ -1057,6 +1386,29 @@ pub fn contains<'a, P: Pattern<'a>>(&'a self, pat: P) -> bool {
         pat.is_contained_in(self)
     }
 
+    /// Returns `true` if any of the given string slices is contained in this
+    /// string slice.
+    ///
+    /// Returns `false` if none of them is, or if `pats` is empty.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(str_find_any)]
+    ///
+    /// let bananas = "bananas";
+    ///
+    /// assert!(bananas.contains_any(&["apples", "nana"]));
+    /// assert!(!bananas.contains_any(&["apples", "pears"]));
+    /// assert!(!bananas.contains_any(&[]));
+    /// ```
+    #[unstable(feature = "str_find_any", issue = "none")]
+    #[must_use]
+    #[inline]
+    pub fn contains_any(&self, pats: &[&str]) -> bool {
+        pats.iter().any(|pat| self.contains(pat))
+    }
+
     /// Returns `true` if the given pattern matches a prefix of this
     /// string slice.
     ///
@@ -1083,6 +1435,32 @@ pub fn starts_with<'a, P: Pattern<'a>>(&'a self, pat: P) -> bool {
         pat.is_prefix_of(self)
     }
 
+    /// Returns the index into `prefixes` of the first string slice that is a
+    /// prefix of this string slice.
+    ///
+    /// Entries are tried in order, so when several of them match, the one
+    /// listed earliest wins, even if a later one is longer.
+    ///
+    /// Returns [`None`] if none of them is a prefix, or if `prefixes` is empty.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(str_starts_with_any)]
+    ///
+    /// let s = "foobar";
+    ///
+    /// assert_eq!(s.starts_with_any(&["bar", "foo"]), Some(1));
+    /// assert_eq!(s.starts_with_any(&["foo", "foobar"]), Some(0));
+    /// assert_eq!(s.starts_with_any(&["bar", "baz"]), None);
+    /// ```
+    #[unstable(feature = "str_starts_with_any", issue = "none")]
+    #[must_use]
+    #[inline]
+    pub fn starts_with_any(&self, prefixes: &[&str]) -> Option<usize> {
+        prefixes.iter().position(|prefix| self.starts_with(prefix))
+    }
+
     /// Returns `true` if the given pattern matches a suffix of this
     /// string slice.
     ///
@@ -1112,6 +1490,70 @@ pub fn ends_with<'a, P>(&'a self, pat: P) -> bool
         pat.is_suffix_of(self)
     }
 
+    /// Returns the index into `suffixes` of the first string slice that is a
+    /// suffix of this string slice.
+    ///
+    /// Entries are tried in order, so when several of them match, the one
+    /// listed earliest wins, even if a later one is longer.
+    ///
+    /// Returns [`None`] if none of them is a suffix, or if `suffixes` is empty.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(str_starts_with_any)]
+    ///
+    /// let s = "foobar";
+    ///
+    /// assert_eq!(s.ends_with_any(&["foo", "bar"]), Some(1));
+    /// assert_eq!(s.ends_with_any(&["bar", "foobar"]), Some(0));
+    /// assert_eq!(s.ends_with_any(&["foo", "baz"]), None);
+    /// ```
+    #[unstable(feature = "str_starts_with_any", issue = "none")]
+    #[must_use]
+    #[inline]
+    pub fn ends_with_any(&self, suffixes: &[&str]) -> Option<usize> {
+        suffixes.iter().position(|suffix| self.ends_with(suffix))
+    }
+
+    /// Checks whether the given pattern matches this string slice starting
+    /// exactly at byte offset `at`, returning the byte offset just past the
+    /// match if it does.
+    ///
+    /// Returns [`None`] if the pattern doesn't match at `at`. Unlike [`find`],
+    /// this never searches any further into the string, which makes it
+    /// suitable for parsers that keep track of a cursor.
+    ///
+    /// The [pattern] can be a `&str`, [`char`], a slice of [`char`]s, or a
+    /// function or closure that determines if a character matches.
+    ///
+    /// [`char`]: prim@char
+    /// [pattern]: self::pattern
+    /// [`find`]: str::find
+    ///
+    /// # Panics
+    ///
+    /// Panics if `at` is not on a UTF-8 code point boundary, or if it is past
+    /// the end of the string slice.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(str_matches_at)]
+    /// let s = "foobar";
+    ///
+    /// assert_eq!(s.matches_at(3, "bar"), Some(6));
+    /// assert_eq!(s.matches_at(2, "bar"), None);
+    /// assert_eq!(s.matches_at(0, char::is_alphabetic), Some(1));
+    /// assert_eq!(s.matches_at(6, ""), Some(6));
+    /// ```
+    #[must_use]
+    #[unstable(feature = "str_matches_at", issue = "none")]
+    pub fn matches_at<'a, P: Pattern<'a>>(&'a self, at: usize, pat: P) -> Option<usize> {
+        let rest = pat.strip_prefix_of(&self[at..])?;
+        Some(self.len() - rest.len())
+    }
+
     /// Returns the byte index of the first character of this string slice that
     /// matches the pattern.
     ///
@@ -1209,54 +1651,205 @@ pub fn rfind<'a, P>(&'a self, pat: P) -> Option<usize>
         pat.into_searcher(self).next_match_back().map(|(i, _)| i)
     }
 
-    /// An iterator over substrings of this string slice, separated by
-    /// characters matched by a pattern.
+    /// Returns the byte index of the earliest match of any of the given string
+    /// slices in this string slice, along with the index into `pats` of the
+    /// pattern that matched.
     ///
-    /// The [pattern] can be a `&str`, [`char`], a slice of [`char`]s, or a
-    /// function or closure that determines if a character matches.
+    /// If several patterns match at the same earliest position, the one that
+    /// comes first in `pats` is reported.
     ///
-    /// [`char`]: prim@char
-    /// [pattern]: self::pattern
+    /// Returns [`None`] if none of the patterns match, or if `pats` is empty.
     ///
-    /// # Iterator behavior
+    /// # Examples
     ///
-    /// The returned iterator will be a [`DoubleEndedIterator`] if the pattern
-    /// allows a reverse search and forward/reverse search yields the same
-    /// elements. This is true for, e.g., [`char`], but not for `&str`.
+    /// ```
+    /// #![feature(str_find_any)]
     ///
-    /// If the pattern allows a reverse search but its results might differ
-    /// from a forward search, the [`rsplit`] method can be used.
+    /// let s = "Löwe 老虎 Léopard";
     ///
-    /// [`rsplit`]: str::rsplit
+    /// assert_eq!(s.find_any(&["pard", "老虎", "Lé"]), Some((6, 1)));
+    /// assert_eq!(s.find_any(&["Lé", "L"]), Some((0, 1)));
+    /// assert_eq!(s.find_any(&["L", "Lö"]), Some((0, 0)));
+    /// assert_eq!(s.find_any(&["tiger", "lion"]), None);
+    /// ```
+    #[unstable(feature = "str_find_any", issue = "none")]
+    #[must_use]
+    pub fn find_any(&self, pats: &[&str]) -> Option<(usize, usize)> {
+        let mut earliest: Option<(usize, usize)> = None;
+        for (index, pat) in pats.iter().enumerate() {
+            if let Some((start, _)) = pat.into_searcher(self).next_match() {
+                // Only a strictly earlier match replaces the current one, so
+                // that ties go to the pattern listed first.
+                if earliest.map_or(true, |(earliest_start, _)| start < earliest_start) {
+                    earliest = Some((start, index));
+                }
+            }
+        }
+        earliest
+    }
+
+    /// Returns the byte index of the first character for which `f` returns
+    /// [`Some`], along with the value it returned.
+    ///
+    /// Returns [`None`] if `f` returns [`None`] for every character.
     ///
     /// # Examples
     ///
-    /// Simple patterns:
+    /// ```
+    /// #![feature(str_find_map_byte)]
     ///
+    /// assert_eq!("a1b".find_map_byte(|c| c.to_digit(10)), Some((1, 1)));
+    /// assert_eq!("Löwe 7".find_map_byte(|c| c.to_digit(10)), Some((6, 7)));
+    /// assert_eq!("abc".find_map_byte(|c| c.to_digit(10)), None);
     /// ```
-    /// let v: Vec<&str> = "Mary had a little lamb".split(' ').collect();
-    /// assert_eq!(v, ["Mary", "had", "a", "little", "lamb"]);
+    #[unstable(feature = "str_find_map_byte", issue = "none")]
+    #[inline]
+    pub fn find_map_byte<B, F>(&self, mut f: F) -> Option<(usize, B)>
+    where
+        F: FnMut(char) -> Option<B>,
+    {
+        self.char_indices().find_map(|(i, c)| f(c).map(|b| (i, b)))
+    }
+
+    /// Returns the byte index of the first occurrence of `c` in this string
+    /// slice, or [`None`] if it does not occur.
     ///
-    /// let v: Vec<&str> = "".split('X').collect();
-    /// assert_eq!(v, [""]);
+    /// This gives the same result as `self.find(c)`, but always runs a byte
+    /// search over the UTF-8 encoding of `c` instead of decoding `self` one
+    /// char at a time.
     ///
-    /// let v: Vec<&str> = "lionXXtigerXleopard".split('X').collect();
-    /// assert_eq!(v, ["lion", "", "tiger", "leopard"]);
+    /// # Examples
     ///
-    /// let v: Vec<&str> = "lion::tiger::leopard".split("::").collect();
-    /// assert_eq!(v, ["lion", "tiger", "leopard"]);
+    /// ```
+    /// #![feature(str_find_char)]
     ///
-    /// let v: Vec<&str> = "abc1def2ghi".split(char::is_numeric).collect();
-    /// assert_eq!(v, ["abc", "def", "ghi"]);
+    /// let s = "Löwe 老虎 Léopard";
     ///
-    /// let v: Vec<&str> = "lionXtigerXleopard".split(char::is_uppercase).collect();
-    /// assert_eq!(v, ["lion", "tiger", "leopard"]);
+    /// assert_eq!(s.find_char('L'), Some(0));
+    /// assert_eq!(s.find_char('虎'), Some(9));
+    /// assert_eq!(s.find_char('x'), None);
     /// ```
+    #[unstable(feature = "str_find_char", issue = "none")]
+    #[must_use]
+    #[inline]
+    pub fn find_char(&self, c: char) -> Option<usize> {
+        if c.is_ascii() {
+            slice::memchr::memchr(c as u8, self.as_bytes())
+        } else {
+            // `CharSearcher::next_match` memchrs for the last byte of `c`
+            // and then compares the whole encoding.
+            c.into_searcher(self).next_match().map(|(i, _)| i)
+        }
+    }
+
+    /// Returns the byte index of the last occurrence of `c` in this string
+    /// slice, or [`None`] if it does not occur.
     ///
-    /// If the pattern is a slice of chars, split on each occurrence of any of the characters:
+    /// This gives the same result as `self.rfind(c)`; see [`find_char`].
     ///
-    /// ```
-    /// let v: Vec<&str> = "2020-11-03 23:59".split(&['-', ' ', ':', '@'][..]).collect();
+    /// [`find_char`]: str::find_char
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(str_find_char)]
+    ///
+    /// let s = "Löwe 老虎 Léopard";
+    ///
+    /// assert_eq!(s.rfind_char('L'), Some(13));
+    /// assert_eq!(s.rfind_char('é'), Some(14));
+    /// assert_eq!(s.rfind_char('x'), None);
+    /// ```
+    #[unstable(feature = "str_find_char", issue = "none")]
+    #[must_use]
+    #[inline]
+    pub fn rfind_char(&self, c: char) -> Option<usize> {
+        if c.is_ascii() {
+            slice::memchr::memrchr(c as u8, self.as_bytes())
+        } else {
+            c.into_searcher(self).next_match_back().map(|(i, _)| i)
+        }
+    }
+
+    /// Returns the index of the first occurrence of the byte `b` in this
+    /// string slice, or [`None`] if it does not occur.
+    ///
+    /// This gives the same result as `self.bytes().position(|x| x == b)`, but
+    /// is guaranteed to run a fast byte search rather than relying on the
+    /// optimizer to recognize the loop.
+    ///
+    /// If `b` is not ASCII, the returned index lies inside a multi-byte
+    /// character rather than on a char boundary; use [`find_char`] to search
+    /// for a whole character instead.
+    ///
+    /// [`find_char`]: str::find_char
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(str_byte_position)]
+    ///
+    /// let s = "key=value";
+    ///
+    /// assert_eq!(s.byte_position(b'='), Some(3));
+    /// assert_eq!(s.byte_position(b';'), None);
+    /// assert_eq!("é".byte_position(0xA9), Some(1));
+    /// ```
+    #[unstable(feature = "str_byte_position", issue = "none")]
+    #[must_use]
+    #[inline]
+    pub fn byte_position(&self, b: u8) -> Option<usize> {
+        slice::memchr::memchr(b, self.as_bytes())
+    }
+
+    /// An iterator over substrings of this string slice, separated by
+    /// characters matched by a pattern.
+    ///
+    /// The [pattern] can be a `&str`, [`char`], a slice of [`char`]s, or a
+    /// function or closure that determines if a character matches.
+    ///
+    /// [`char`]: prim@char
+    /// [pattern]: self::pattern
+    ///
+    /// # Iterator behavior
+    ///
+    /// The returned iterator will be a [`DoubleEndedIterator`] if the pattern
+    /// allows a reverse search and forward/reverse search yields the same
+    /// elements. This is true for, e.g., [`char`], but not for `&str`.
+    ///
+    /// If the pattern allows a reverse search but its results might differ
+    /// from a forward search, the [`rsplit`] method can be used.
+    ///
+    /// [`rsplit`]: str::rsplit
+    ///
+    /// # Examples
+    ///
+    /// Simple patterns:
+    ///
+    /// ```
+    /// let v: Vec<&str> = "Mary had a little lamb".split(' ').collect();
+    /// assert_eq!(v, ["Mary", "had", "a", "little", "lamb"]);
+    ///
+    /// let v: Vec<&str> = "".split('X').collect();
+    /// assert_eq!(v, [""]);
+    ///
+    /// let v: Vec<&str> = "lionXXtigerXleopard".split('X').collect();
+    /// assert_eq!(v, ["lion", "", "tiger", "leopard"]);
+    ///
+    /// let v: Vec<&str> = "lion::tiger::leopard".split("::").collect();
+    /// assert_eq!(v, ["lion", "tiger", "leopard"]);
+    ///
+    /// let v: Vec<&str> = "abc1def2ghi".split(char::is_numeric).collect();
+    /// assert_eq!(v, ["abc", "def", "ghi"]);
+    ///
+    /// let v: Vec<&str> = "lionXtigerXleopard".split(char::is_uppercase).collect();
+    /// assert_eq!(v, ["lion", "tiger", "leopard"]);
+    /// ```
+    ///
+    /// If the pattern is a slice of chars, split on each occurrence of any of the characters:
+    ///
+    /// ```
+    /// let v: Vec<&str> = "2020-11-03 23:59".split(&['-', ' ', ':', '@'][..]).collect();
     /// assert_eq!(v, ["2020", "11", "03", "23", "59"]);
     /// ```
     ///
@@ -1374,6 +1967,376 @@ pub fn split_inclusive<'a, P: Pattern<'a>>(&'a self, pat: P) -> SplitInclusive<'
         })
     }
 
+    /// An iterator over substrings of this string slice, separated by
+    /// characters matched by a pattern, with the matched part as the
+    /// terminator of each substring. Differs from the iterator produced by
+    /// [`split_inclusive`] in that a match at the end of the string is
+    /// followed by an empty last substring.
+    ///
+    /// This makes it possible to tell whether the string ends with a
+    /// terminator. As with [`split`], an empty string yields a single empty
+    /// substring.
+    ///
+    /// The [pattern] can be a `&str`, [`char`], a slice of [`char`]s, or a
+    /// function or closure that determines if a character matches.
+    ///
+    /// [`char`]: prim@char
+    /// [pattern]: self::pattern
+    /// [`split`]: str::split
+    /// [`split_inclusive`]: str::split_inclusive
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(str_split_inclusive_retain_empty)]
+    /// let v: Vec<&str> = "a\nb\n".split_inclusive_retain_empty('\n').collect();
+    /// assert_eq!(v, ["a\n", "b\n", ""]);
+    ///
+    /// let v: Vec<&str> = "a\nb".split_inclusive_retain_empty('\n').collect();
+    /// assert_eq!(v, ["a\n", "b"]);
+    /// ```
+    #[unstable(feature = "str_split_inclusive_retain_empty", issue = "none")]
+    #[inline]
+    pub fn split_inclusive_retain_empty<'a, P: Pattern<'a>>(
+        &'a self,
+        pat: P,
+    ) -> SplitInclusive<'a, P> {
+        SplitInclusive(SplitInternal {
+            start: 0,
+            end: self.len(),
+            matcher: pat.into_searcher(self),
+            allow_trailing_empty: true,
+            finished: false,
+        })
+    }
+
+    /// An iterator over substrings of this string slice, separated by
+    /// characters matched by a pattern. Differs from the iterator produced by
+    /// [`split_inclusive`] in that a run of consecutive matches terminates a
+    /// single substring, instead of producing one substring per match.
+    ///
+    /// As with [`split_inclusive`], no empty substring is yielded at the end
+    /// of the string.
+    ///
+    /// The [pattern] can be a `&str`, [`char`], a slice of [`char`]s, or a
+    /// function or closure that determines if a character matches.
+    ///
+    /// [`char`]: prim@char
+    /// [pattern]: self::pattern
+    /// [`split_inclusive`]: str::split_inclusive
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(str_split_inclusive_coalesced)]
+    /// let v: Vec<&str> = "a,,b".split_inclusive_coalesced(',').collect();
+    /// assert_eq!(v, ["a,,", "b"]);
+    ///
+    /// let v: Vec<&str> = "a,,b".split_inclusive(',').collect();
+    /// assert_eq!(v, ["a,", ",", "b"]);
+    /// ```
+    ///
+    /// A run of matches at the start of the string forms a substring of its
+    /// own:
+    ///
+    /// ```
+    /// #![feature(str_split_inclusive_coalesced)]
+    /// let v: Vec<&str> = "\n\nfoo\nbar\n\n".split_inclusive_coalesced('\n').collect();
+    /// assert_eq!(v, ["\n\n", "foo\n", "bar\n\n"]);
+    /// ```
+    #[unstable(feature = "str_split_inclusive_coalesced", issue = "none")]
+    #[inline]
+    pub fn split_inclusive_coalesced<'a, P: Pattern<'a>>(
+        &'a self,
+        pat: P,
+    ) -> SplitInclusiveCoalesced<'a, P> {
+        SplitInclusiveCoalesced {
+            start: 0,
+            matcher: pat.into_searcher(self),
+            pending: None,
+            finished: false,
+        }
+    }
+
+    /// An iterator over substrings of this string slice, separated by any of
+    /// the given chars, each paired with the char that ended it.
+    ///
+    /// This yields the same substrings as `self.split(chars)`, paired with the
+    /// separator that follows each of them, or with `None` for the last one.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(str_split_on_any_indexed)]
+    /// let v: Vec<_> = "a,b;c".split_on_any_indexed(&[',', ';']).collect();
+    /// assert_eq!(v, [("a", Some(',')), ("b", Some(';')), ("c", None)]);
+    ///
+    /// let v: Vec<_> = "a,;".split_on_any_indexed(&[',', ';']).collect();
+    /// assert_eq!(v, [("a", Some(',')), ("", Some(';')), ("", None)]);
+    /// ```
+    #[unstable(feature = "str_split_on_any_indexed", issue = "none")]
+    #[inline]
+    pub fn split_on_any_indexed<'a, 'b>(&'a self, chars: &'b [char]) -> SplitOnAnyIndexed<'a, 'b> {
+        SplitOnAnyIndexed { inner: self.split(chars) }
+    }
+
+    /// An iterator over substrings of this string slice, separated by any of
+    /// the chars in `set`.
+    ///
+    /// This is the same as `self.split(set)`, spelled out for readers who
+    /// don't know that a slice of chars is a [pattern]. To also learn which
+    /// char ended each substring, use [`split_on_any_indexed`].
+    ///
+    /// [pattern]: self::pattern
+    /// [`split_on_any_indexed`]: str::split_on_any_indexed
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(str_char_set)]
+    /// let v: Vec<&str> = "a,b;c|d".split_on_char_set(&[',', ';', '|']).collect();
+    /// assert_eq!(v, ["a", "b", "c", "d"]);
+    /// ```
+    #[unstable(feature = "str_char_set", issue = "none")]
+    #[inline]
+    pub fn split_on_char_set<'a, 'b>(&'a self, set: &'b [char]) -> Split<'a, &'b [char]> {
+        self.split(set)
+    }
+
+    /// An iterator over the matches of any of the chars in `set` within this
+    /// string slice, yielding the byte index of each match and the char that
+    /// matched.
+    ///
+    /// This is like `self.match_indices(set)`, but yields the matched char
+    /// itself instead of a one-char string slice.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(str_char_set)]
+    /// let v: Vec<_> = "a,b;c|d".match_indices_char_set(&[',', ';', '|']).collect();
+    /// assert_eq!(v, [(1, ','), (3, ';'), (5, '|')]);
+    ///
+    /// let v: Vec<_> = "Löwe, 老虎".match_indices_char_set(&['ö', '虎']).rev().collect();
+    /// assert_eq!(v, [(10, '虎'), (1, 'ö')]);
+    /// ```
+    #[unstable(feature = "str_char_set", issue = "none")]
+    #[inline]
+    pub fn match_indices_char_set<'a, 'b>(
+        &'a self,
+        set: &'b [char],
+    ) -> MatchIndicesCharSet<'a, 'b> {
+        MatchIndicesCharSet { inner: self.match_indices(set) }
+    }
+
+    /// An iterator over substrings of this string slice, separated by
+    /// characters matched by a pattern, each paired with the separator that
+    /// follows it.
+    ///
+    /// This yields the same substrings as `self.split(pat)`, paired with the
+    /// match that ended each of them, or with `None` for the last one.
+    /// Concatenating every piece and separator in order reproduces `self`.
+    ///
+    /// The [pattern] can be a `&str`, [`char`], a slice of [`char`]s, or a
+    /// function or closure that determines if a character matches.
+    ///
+    /// [`char`]: prim@char
+    /// [pattern]: self::pattern
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(str_split_with_separators)]
+    /// let v: Vec<_> = "a, b,c".split_with_separators(',').collect();
+    /// assert_eq!(v, [("a", Some(",")), (" b", Some(",")), ("c", None)]);
+    ///
+    /// let s = "k=v;; x=y";
+    /// let mut rebuilt = String::new();
+    /// for (piece, separator) in s.split_with_separators(';') {
+    ///     rebuilt.push_str(piece);
+    ///     rebuilt.push_str(separator.unwrap_or(""));
+    /// }
+    /// assert_eq!(rebuilt, s);
+    ///
+    /// let v: Vec<_> = "".split_with_separators(',').collect();
+    /// assert_eq!(v, [("", None)]);
+    /// ```
+    #[unstable(feature = "str_split_with_separators", issue = "none")]
+    #[inline]
+    pub fn split_with_separators<'a, P: Pattern<'a>>(
+        &'a self,
+        pat: P,
+    ) -> SplitWithSeparators<'a, P> {
+        SplitWithSeparators { start: 0, matcher: pat.into_searcher(self), finished: false }
+    }
+
+    /// An iterator over substrings of this string slice, split between each
+    /// pair of adjacent characters for which `f` returns `true`.
+    ///
+    /// `f` is called with every pair of neighbouring characters, in order.
+    /// Every yielded substring holds at least one character, so an empty
+    /// string yields nothing.
+    ///
+    /// # Examples
+    ///
+    /// Splitting camelCase identifiers into words:
+    ///
+    /// ```
+    /// #![feature(str_split_at_boundaries)]
+    /// let v: Vec<&str> = "fooBarBaz"
+    ///     .split_at_boundaries(|a, b| a.is_lowercase() && b.is_uppercase())
+    ///     .collect();
+    /// assert_eq!(v, ["foo", "Bar", "Baz"]);
+    /// ```
+    ///
+    /// Splitting wherever the character class changes:
+    ///
+    /// ```
+    /// #![feature(str_split_at_boundaries)]
+    /// let v: Vec<&str> =
+    ///     "ab12c".split_at_boundaries(|a, b| a.is_numeric() != b.is_numeric()).collect();
+    /// assert_eq!(v, ["ab", "12", "c"]);
+    /// ```
+    #[unstable(feature = "str_split_at_boundaries", issue = "none")]
+    #[inline]
+    pub fn split_at_boundaries<F>(&self, f: F) -> SplitAtBoundaries<'_, F>
+    where
+        F: FnMut(char, char) -> bool,
+    {
+        SplitAtBoundaries { remainder: self, f }
+    }
+
+    /// An iterator over substrings of this string slice, separated by
+    /// characters matched by a pattern, with leading and trailing whitespace
+    /// removed from each substring.
+    ///
+    /// This is equivalent to `self.split(pat).map(str::trim)`. The yielded
+    /// substrings still borrow from `self`.
+    ///
+    /// 'Whitespace' is defined according to the terms of the Unicode Derived
+    /// Core Property `White_Space`, as in [`trim`].
+    ///
+    /// The [pattern] can be a `&str`, [`char`], a slice of [`char`]s, or a
+    /// function or closure that determines if a character matches.
+    ///
+    /// [`char`]: prim@char
+    /// [pattern]: self::pattern
+    /// [`trim`]: str::trim
+    ///
+    /// # Iterator behavior
+    ///
+    /// The returned iterator will be a [`DoubleEndedIterator`] if the pattern
+    /// allows a reverse search and forward/reverse search yields the same
+    /// elements. This is true for, e.g., [`char`], but not for `&str`.
+    ///
+    /// Substrings that consist only of whitespace are yielded as `""`; use
+    /// [`Iterator::filter`] to skip them.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(str_split_trimmed)]
+    ///
+    /// let v: Vec<&str> = " a , b ,c ".split_trimmed(',').collect();
+    /// assert_eq!(v, ["a", "b", "c"]);
+    ///
+    /// let v: Vec<&str> = "a,  ,b".split_trimmed(',').collect();
+    /// assert_eq!(v, ["a", "", "b"]);
+    /// ```
+    #[unstable(feature = "str_split_trimmed", issue = "none")]
+    #[inline]
+    pub fn split_trimmed<'a, P: Pattern<'a>>(&'a self, pat: P) -> SplitTrimmed<'a, P> {
+        SplitTrimmed { inner: self.split(pat).map(TrimWhitespace) }
+    }
+
+    /// Returns the first substring of this string slice, separated by
+    /// characters matched by a pattern, for which `pred` returns `true`.
+    ///
+    /// This is equivalent to `self.split(pat).find(pred)`, but drives the
+    /// searcher directly and stops searching as soon as a substring is found.
+    ///
+    /// The [pattern] can be a `&str`, [`char`], a slice of [`char`]s, or a
+    /// function or closure that determines if a character matches.
+    ///
+    /// [`char`]: prim@char
+    /// [pattern]: self::pattern
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(str_split_find)]
+    ///
+    /// let s = "lion,tiger,leopard";
+    /// assert_eq!(s.split_find(',', |animal| animal.starts_with('t')), Some("tiger"));
+    /// assert_eq!(s.split_find(',', |animal| animal.len() > 7), None);
+    /// ```
+    #[unstable(feature = "str_split_find", issue = "none")]
+    #[inline]
+    pub fn split_find<'a, P, F>(&'a self, pat: P, mut pred: F) -> Option<&'a str>
+    where
+        P: Pattern<'a>,
+        F: FnMut(&'a str) -> bool,
+    {
+        let mut matcher = pat.into_searcher(self);
+        let mut start = 0;
+        while let Some((a, b)) = matcher.next_match() {
+            // SAFETY: `Searcher` guarantees that `a` and `b` lie on unicode boundaries.
+            let piece = unsafe { self.get_unchecked(start..a) };
+            if pred(piece) {
+                return Some(piece);
+            }
+            start = b;
+        }
+        // SAFETY: `start` is either 0 or the end of a match, so it lies on a
+        // unicode boundary.
+        let piece = unsafe { self.get_unchecked(start..) };
+        if pred(piece) { Some(piece) } else { None }
+    }
+
+    /// Appends the substrings of this string slice, separated by characters
+    /// matched by a pattern, to an existing collection.
+    ///
+    /// This pushes the same sequence of pieces as [`split`] does, but drives
+    /// the searcher directly, so that pieces from several strings can be
+    /// gathered into one collection without an intermediate iterator or
+    /// allocation.
+    ///
+    /// The [pattern] can be a `&str`, [`char`], a slice of [`char`]s, or a
+    /// function or closure that determines if a character matches.
+    ///
+    /// [`split`]: str::split
+    /// [`char`]: prim@char
+    /// [pattern]: self::pattern
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(str_split_extend)]
+    ///
+    /// let mut v = Vec::new();
+    /// "a,b".split_extend(',', &mut v);
+    /// "c,,d".split_extend(',', &mut v);
+    /// assert_eq!(v, ["a", "b", "c", "", "d"]);
+    /// ```
+    #[unstable(feature = "str_split_extend", issue = "none")]
+    #[inline]
+    pub fn split_extend<'a, P, E>(&'a self, pat: P, into: &mut E)
+    where
+        P: Pattern<'a>,
+        E: Extend<&'a str>,
+    {
+        let mut matcher = pat.into_searcher(self);
+        let mut start = 0;
+        while let Some((a, b)) = matcher.next_match() {
+            // SAFETY: `Searcher` guarantees that `a` and `b` lie on unicode boundaries.
+            into.extend_one(unsafe { self.get_unchecked(start..a) });
+            start = b;
+        }
+        // SAFETY: `start` is either 0 or the end of a match, so it lies on a
+        // unicode boundary.
+        into.extend_one(unsafe { self.get_unchecked(start..) });
+    }
+
     /// An iterator over substrings of the given string slice, separated by
     /// characters matched by a pattern and yielded in reverse order.
     ///
@@ -1426,6 +2389,91 @@ pub fn rsplit<'a, P>(&'a self, pat: P) -> RSplit<'a, P>
         RSplit(self.split(pat).0)
     }
 
+    /// An iterator over the byte ranges of the substrings of this string
+    /// slice, separated by characters matched by a pattern.
+    ///
+    /// This yields the same pieces as [`split`], but as ranges of byte
+    /// indices into `self` rather than as string slices. The ranges can be
+    /// used to index into `self` or into other buffers that run parallel to
+    /// it, without keeping `self` borrowed.
+    ///
+    /// The [pattern] can be a `&str`, [`char`], a slice of [`char`]s, or a
+    /// function or closure that determines if a character matches.
+    ///
+    /// [`char`]: prim@char
+    /// [pattern]: self::pattern
+    /// [`split`]: str::split
+    ///
+    /// # Iterator behavior
+    ///
+    /// The returned iterator will be a [`DoubleEndedIterator`] if the pattern
+    /// allows a reverse search and forward/reverse search yields the same
+    /// elements. This is true for, e.g., [`char`], but not for `&str`.
+    ///
+    /// If the pattern allows a reverse search but its results might differ
+    /// from a forward search, the [`rsplit_ranges`] method can be used.
+    ///
+    /// [`rsplit_ranges`]: str::rsplit_ranges
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(str_split_ranges)]
+    /// let v: Vec<_> = "a,bb,c".split_ranges(',').collect();
+    /// assert_eq!(v, [0..1, 2..4, 5..6]);
+    ///
+    /// let v: Vec<_> = "a,,c,".split_ranges(',').collect();
+    /// assert_eq!(v, [0..1, 2..2, 3..4, 5..5]);
+    /// ```
+    #[unstable(feature = "str_split_ranges", issue = "none")]
+    #[inline]
+    pub fn split_ranges<'a, P: Pattern<'a>>(&'a self, pat: P) -> SplitRanges<'a, P> {
+        SplitRanges(SplitRangesInternal(self.split(pat).0))
+    }
+
+    /// An iterator over the byte ranges of the substrings of this string
+    /// slice, separated by characters matched by a pattern and yielded in
+    /// reverse order.
+    ///
+    /// This yields the same pieces as [`rsplit`], but as ranges of byte
+    /// indices into `self` rather than as string slices.
+    ///
+    /// The [pattern] can be a `&str`, [`char`], a slice of [`char`]s, or a
+    /// function or closure that determines if a character matches.
+    ///
+    /// [`char`]: prim@char
+    /// [pattern]: self::pattern
+    /// [`rsplit`]: str::rsplit
+    ///
+    /// # Iterator behavior
+    ///
+    /// The returned iterator requires that the pattern supports a reverse
+    /// search, and it will be a [`DoubleEndedIterator`] if a forward/reverse
+    /// search yields the same elements.
+    ///
+    /// For iterating from the front, the [`split_ranges`] method can be used.
+    ///
+    /// [`split_ranges`]: str::split_ranges
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(str_split_ranges)]
+    /// let v: Vec<_> = "a,bb,c".rsplit_ranges(',').collect();
+    /// assert_eq!(v, [5..6, 2..4, 0..1]);
+    ///
+    /// let v: Vec<_> = "aXXbXXXc".rsplit_ranges("XX").collect();
+    /// assert_eq!(v, [7..8, 3..5, 0..1]);
+    /// ```
+    #[unstable(feature = "str_split_ranges", issue = "none")]
+    #[inline]
+    pub fn rsplit_ranges<'a, P>(&'a self, pat: P) -> RSplitRanges<'a, P>
+    where
+        P: Pattern<'a, Searcher: ReverseSearcher<'a>>,
+    {
+        RSplitRanges(self.split_ranges(pat).0)
+    }
+
     /// An iterator over substrings of the given string slice, separated by
     /// characters matched by a pattern.
     ///
@@ -1627,6 +2675,74 @@ pub fn rsplitn<'a, P>(&'a self, n: usize, pat: P) -> RSplitN<'a, P>
         RSplitN(self.splitn(n, pat).0)
     }
 
+    /// An iterator over substrings of this string slice, separated by at most
+    /// `n` occurrences of a pattern.
+    ///
+    /// Once `n` separators have been consumed, the rest of the string is
+    /// returned as the last substring, so at most `n + 1` substrings are
+    /// returned. This is exactly `self.splitn(n + 1, pat)`; the separator
+    /// count is just often the more natural way to think about the limit.
+    /// In particular, `split_max_seps(0, pat)` returns the whole string.
+    ///
+    /// The [pattern] can be a `&str`, [`char`], a slice of [`char`]s, or a
+    /// function or closure that determines if a character matches.
+    ///
+    /// [`char`]: prim@char
+    /// [pattern]: self::pattern
+    ///
+    /// # Iterator behavior
+    ///
+    /// As with [`splitn`], the returned iterator will not be double ended.
+    /// For splitting from the end, the [`rsplit_max_seps`] method can be used.
+    ///
+    /// [`splitn`]: str::splitn
+    /// [`rsplit_max_seps`]: str::rsplit_max_seps
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(str_split_max_seps)]
+    ///
+    /// let v: Vec<&str> = "key=value=more".split_max_seps(1, '=').collect();
+    /// assert_eq!(v, ["key", "value=more"]);
+    ///
+    /// let v: Vec<&str> = "a,b,".split_max_seps(2, ',').collect();
+    /// assert_eq!(v, ["a", "b", ""]);
+    ///
+    /// let v: Vec<&str> = "a,b".split_max_seps(0, ',').collect();
+    /// assert_eq!(v, ["a,b"]);
+    /// ```
+    #[unstable(feature = "str_split_max_seps", issue = "none")]
+    #[inline]
+    pub fn split_max_seps<'a, P: Pattern<'a>>(&'a self, n: usize, pat: P) -> SplitN<'a, P> {
+        self.splitn(n.saturating_add(1), pat)
+    }
+
+    /// An iterator over substrings of this string slice, separated by at most
+    /// `n` occurrences of a pattern, starting from the end of the string.
+    ///
+    /// This is exactly `self.rsplitn(n + 1, pat)`; see [`split_max_seps`]
+    /// for details.
+    ///
+    /// [`split_max_seps`]: str::split_max_seps
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(str_split_max_seps)]
+    ///
+    /// let v: Vec<&str> = "lion::tiger::leopard".rsplit_max_seps(1, "::").collect();
+    /// assert_eq!(v, ["leopard", "lion::tiger"]);
+    /// ```
+    #[unstable(feature = "str_split_max_seps", issue = "none")]
+    #[inline]
+    pub fn rsplit_max_seps<'a, P>(&'a self, n: usize, pat: P) -> RSplitN<'a, P>
+    where
+        P: Pattern<'a, Searcher: ReverseSearcher<'a>>,
+    {
+        self.rsplitn(n.saturating_add(1), pat)
+    }
+
     /// Splits the string on the first occurrence of the specified delimiter and
     /// returns prefix before delimiter and suffix after delimiter.
     ///
@@ -1667,6 +2783,57 @@ pub fn rsplit_once<'a, P>(&'a self, delimiter: P) -> Option<(&'a str, &'a str)>
         unsafe { Some((self.get_unchecked(..start), self.get_unchecked(end..))) }
     }
 
+    /// Returns the part of the string before the first occurrence of `pat`,
+    /// or the whole string if `pat` does not match.
+    ///
+    /// This is the same as `self.split(pat).next().unwrap()`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(str_match_piece)]
+    /// assert_eq!("a/b/c".first_match_piece('/'), "a");
+    /// assert_eq!("/a/b".first_match_piece('/'), "");
+    /// assert_eq!("abc".first_match_piece('/'), "abc");
+    /// ```
+    #[unstable(feature = "str_match_piece", issue = "none")]
+    #[must_use]
+    #[inline]
+    pub fn first_match_piece<'a, P: Pattern<'a>>(&'a self, pat: P) -> &'a str {
+        match pat.into_searcher(self).next_match() {
+            // SAFETY: `Searcher` is known to return valid indices.
+            Some((start, _)) => unsafe { self.get_unchecked(..start) },
+            None => self,
+        }
+    }
+
+    /// Returns the part of the string after the last occurrence of `pat`,
+    /// or the whole string if `pat` does not match.
+    ///
+    /// This is the same as `self.rsplit(pat).next().unwrap()`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(str_match_piece)]
+    /// assert_eq!("a/b/c".last_match_piece('/'), "c");
+    /// assert_eq!("a/b/".last_match_piece('/'), "");
+    /// assert_eq!("abc".last_match_piece('/'), "abc");
+    /// ```
+    #[unstable(feature = "str_match_piece", issue = "none")]
+    #[must_use]
+    #[inline]
+    pub fn last_match_piece<'a, P>(&'a self, pat: P) -> &'a str
+    where
+        P: Pattern<'a, Searcher: ReverseSearcher<'a>>,
+    {
+        match pat.into_searcher(self).next_match_back() {
+            // SAFETY: `Searcher` is known to return valid indices.
+            Some((_, end)) => unsafe { self.get_unchecked(end..) },
+            None => self,
+        }
+    }
+
     /// An iterator over the disjoint matches of a pattern within the given string
     /// slice.
     ///
@@ -1701,7 +2868,7 @@ pub fn rsplit_once<'a, P>(&'a self, delimiter: P) -> Option<(&'a str, &'a str)>
     #[stable(feature = "str_matches", since = "1.2.0")]
     #[inline]
     pub fn matches<'a, P: Pattern<'a>>(&'a self, pat: P) -> Matches<'a, P> {
-        Matches(MatchesInternal(pat.into_searcher(self)))
+        Matches(MatchesInternal { start: 0, end: self.len(), matcher: pat.into_searcher(self) })
     }
 
     /// An iterator over the disjoint matches of a pattern within this string slice,
@@ -1831,6 +2998,174 @@ pub fn rmatch_indices<'a, P>(&'a self, pat: P) -> RMatchIndices<'a, P>
         RMatchIndices(self.match_indices(pat).0)
     }
 
+    /// An iterator over the byte ranges of the disjoint matches of a pattern
+    /// within this string slice.
+    ///
+    /// This yields the same matches as [`match_indices`], as `start..end`
+    /// ranges of byte indices into `self`. The ranges never overlap and come
+    /// in increasing order. For matches of `pat` within `self` that overlap,
+    /// only the range of the first match is returned.
+    ///
+    /// The [pattern] can be a `&str`, [`char`], a slice of [`char`]s, or a
+    /// function or closure that determines if a character matches.
+    ///
+    /// [`char`]: prim@char
+    /// [pattern]: self::pattern
+    /// [`match_indices`]: str::match_indices
+    ///
+    /// # Iterator behavior
+    ///
+    /// The returned iterator will be a [`DoubleEndedIterator`] if the pattern
+    /// allows a reverse search and forward/reverse search yields the same
+    /// elements. This is true for, e.g., [`char`], but not for `&str`.
+    ///
+    /// If the pattern allows a reverse search but its results might differ
+    /// from a forward search, the [`rmatch_ranges`] method can be used.
+    ///
+    /// [`rmatch_ranges`]: str::rmatch_ranges
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(str_match_ranges)]
+    /// let v: Vec<_> = "a1b2".match_ranges(char::is_numeric).collect();
+    /// assert_eq!(v, [1..2, 3..4]);
+    ///
+    /// let v: Vec<_> = "ababa".match_ranges("aba").collect();
+    /// assert_eq!(v, [0..3]); // only the first `aba`
+    /// ```
+    #[unstable(feature = "str_match_ranges", issue = "none")]
+    #[inline]
+    pub fn match_ranges<'a, P: Pattern<'a>>(&'a self, pat: P) -> MatchRanges<'a, P> {
+        MatchRanges(MatchRangesInternal(pat.into_searcher(self)))
+    }
+
+    /// An iterator over the byte ranges of the disjoint matches of a pattern
+    /// within this string slice, yielded in reverse order.
+    ///
+    /// This yields the same matches as [`rmatch_indices`], as `start..end`
+    /// ranges of byte indices into `self`. For matches of `pat` within `self`
+    /// that overlap, only the range of the last match is returned.
+    ///
+    /// The [pattern] can be a `&str`, [`char`], a slice of [`char`]s, or a
+    /// function or closure that determines if a character matches.
+    ///
+    /// [`char`]: prim@char
+    /// [pattern]: self::pattern
+    /// [`rmatch_indices`]: str::rmatch_indices
+    ///
+    /// # Iterator behavior
+    ///
+    /// The returned iterator requires that the pattern supports a reverse
+    /// search, and it will be a [`DoubleEndedIterator`] if a forward/reverse
+    /// search yields the same elements.
+    ///
+    /// For iterating from the front, the [`match_ranges`] method can be used.
+    ///
+    /// [`match_ranges`]: str::match_ranges
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(str_match_ranges)]
+    /// let v: Vec<_> = "a1b2".rmatch_ranges(char::is_numeric).collect();
+    /// assert_eq!(v, [3..4, 1..2]);
+    ///
+    /// let v: Vec<_> = "ababa".rmatch_ranges("aba").collect();
+    /// assert_eq!(v, [2..5]); // only the last `aba`
+    /// ```
+    #[unstable(feature = "str_match_ranges", issue = "none")]
+    #[inline]
+    pub fn rmatch_ranges<'a, P>(&'a self, pat: P) -> RMatchRanges<'a, P>
+    where
+        P: Pattern<'a, Searcher: ReverseSearcher<'a>>,
+    {
+        RMatchRanges(self.match_ranges(pat).0)
+    }
+
+    /// An iterator over the disjoint matches of a pattern within `self`,
+    /// each paired with a slice of `self` that surrounds it.
+    ///
+    /// The context slice contains the match itself, extended by up to
+    /// `before` characters to its left and up to `after` characters to its
+    /// right. It is clamped to the bounds of `self`, and since it is counted
+    /// in characters rather than bytes it always lies on char boundaries.
+    ///
+    /// Matches are found exactly as by [`match_indices`], so context windows
+    /// of neighboring matches may overlap.
+    ///
+    /// The [pattern] can be a `&str`, [`char`], a slice of [`char`]s, or a
+    /// function or closure that determines if a character matches.
+    ///
+    /// [`char`]: prim@char
+    /// [pattern]: self::pattern
+    /// [`match_indices`]: str::match_indices
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(str_matches_context)]
+    /// let v: Vec<_> = "let x = foo + bar;".matches_context('+', 4, 4).collect();
+    /// assert_eq!(v, [("+", "foo + bar")]);
+    ///
+    /// let v: Vec<_> = "a1b2".matches_context(char::is_numeric, 1, 1).collect();
+    /// assert_eq!(v, [("1", "a1b"), ("2", "b2")]);
+    ///
+    /// let v: Vec<_> = "αβγ".matches_context('β', 5, 0).collect();
+    /// assert_eq!(v, [("β", "αβ")]);
+    /// ```
+    #[unstable(feature = "str_matches_context", issue = "none")]
+    #[inline]
+    pub fn matches_context<'a, P: Pattern<'a>>(
+        &'a self,
+        pat: P,
+        before: usize,
+        after: usize,
+    ) -> MatchesContext<'a, P> {
+        MatchesContext { haystack: self, inner: self.match_indices(pat), before, after }
+    }
+
+    /// An iterator over the disjoint matches of a pattern within `self`,
+    /// interleaved with the substrings between them.
+    ///
+    /// Matches are yielded as [`MatchOrGap::Match`] and the text between
+    /// them as [`MatchOrGap::Gap`], in order, so the pieces concatenate back
+    /// to `self`. Gaps are never empty: two adjacent matches, or a match at
+    /// either end of `self`, have no gap between them.
+    ///
+    /// Matches are found exactly as by [`matches`].
+    ///
+    /// The [pattern] can be a `&str`, [`char`], a slice of [`char`]s, or a
+    /// function or closure that determines if a character matches.
+    ///
+    /// [`char`]: prim@char
+    /// [pattern]: self::pattern
+    /// [`matches`]: str::matches
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(str_matches_and_gaps)]
+    /// use std::str::MatchOrGap::{Gap, Match};
+    ///
+    /// let v: Vec<_> = "x = 12+3".matches_and_gaps(char::is_numeric).collect();
+    /// assert_eq!(v, [Gap("x = "), Match("1"), Match("2"), Gap("+"), Match("3")]);
+    ///
+    /// let v: Vec<_> = "abc".matches_and_gaps('-').collect();
+    /// assert_eq!(v, [Gap("abc")]);
+    /// ```
+    #[unstable(feature = "str_matches_and_gaps", issue = "none")]
+    #[inline]
+    pub fn matches_and_gaps<'a, P: Pattern<'a>>(&'a self, pat: P) -> MatchesAndGaps<'a, P> {
+        MatchesAndGaps {
+            haystack: self,
+            searcher: pat.into_searcher(self),
+            position: 0,
+            pending: None,
+            finished: false,
+        }
+    }
+
     /// Returns a string slice with leading and trailing whitespace removed.
     ///
     /// 'Whitespace' is defined according to the terms of the Unicode Derived
@@ -1905,31 +3240,105 @@ pub fn trim_start(&self) -> &str {
     /// Russian, this will be right side, and for right-to-left languages like
     /// Arabic or Hebrew, this will be the left side.
     ///
-    /// # Examples
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// let s = "\n Hello\tworld\t\n";
+    /// assert_eq!("\n Hello\tworld", s.trim_end());
+    /// ```
+    ///
+    /// Directionality:
+    ///
+    /// ```
+    /// let s = "  English  ";
+    /// assert!(Some('h') == s.trim_end().chars().rev().next());
+    ///
+    /// let s = "  עברית  ";
+    /// assert!(Some('ת') == s.trim_end().chars().rev().next());
+    /// ```
+    #[inline]
+    #[must_use = "this returns the trimmed string as a new slice, \
+                  without modifying the original"]
+    #[stable(feature = "trim_direction", since = "1.30.0")]
+    #[cfg_attr(not(test), rustc_diagnostic_item = "str_trim_end")]
+    pub fn trim_end(&self) -> &str {
+        self.trim_end_matches(|c: char| c.is_whitespace())
+    }
+
+    /// Returns the byte range of this string slice that [`trim`] would
+    /// return, with leading and trailing whitespace removed.
+    ///
+    /// This lets positions in the trimmed string be mapped back to positions
+    /// in `self`. If the string is all whitespace, the range is `0..0`, which
+    /// matches where the empty slice returned by [`trim`] starts.
+    ///
+    /// [`trim`]: str::trim
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(str_trim_range)]
+    /// let s = "  abc  ";
+    /// assert_eq!(s.trim_range(), 2..5);
+    /// assert_eq!(&s[s.trim_range()], s.trim());
+    ///
+    /// assert_eq!(" \t\n".trim_range(), 0..0);
+    /// ```
+    #[inline]
+    #[must_use]
+    #[unstable(feature = "str_trim_range", issue = "none")]
+    pub fn trim_range(&self) -> Range<usize> {
+        let trimmed = self.trim();
+        let start = trimmed.as_ptr() as usize - self.as_ptr() as usize;
+        start..start + trimmed.len()
+    }
+
+    /// Returns the byte range of this string slice that [`trim_start`] would
+    /// return, with leading whitespace removed.
+    ///
+    /// If the string is all whitespace, the range is `len..len`, which
+    /// matches where the empty slice returned by [`trim_start`] starts.
     ///
-    /// Basic usage:
+    /// [`trim_start`]: str::trim_start
+    ///
+    /// # Examples
     ///
     /// ```
-    /// let s = "\n Hello\tworld\t\n";
-    /// assert_eq!("\n Hello\tworld", s.trim_end());
+    /// #![feature(str_trim_range)]
+    /// assert_eq!("  abc  ".trim_start_range(), 2..7);
+    /// assert_eq!(" \t\n".trim_start_range(), 3..3);
     /// ```
+    #[inline]
+    #[must_use]
+    #[unstable(feature = "str_trim_range", issue = "none")]
+    pub fn trim_start_range(&self) -> Range<usize> {
+        let trimmed = self.trim_start();
+        let start = trimmed.as_ptr() as usize - self.as_ptr() as usize;
+        start..self.len()
+    }
+
+    /// Returns the byte range of this string slice that [`trim_end`] would
+    /// return, with trailing whitespace removed.
     ///
-    /// Directionality:
+    /// If the string is all whitespace, the range is `0..0`, which matches
+    /// where the empty slice returned by [`trim_end`] starts.
     ///
-    /// ```
-    /// let s = "  English  ";
-    /// assert!(Some('h') == s.trim_end().chars().rev().next());
+    /// [`trim_end`]: str::trim_end
     ///
-    /// let s = "  עברית  ";
-    /// assert!(Some('ת') == s.trim_end().chars().rev().next());
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(str_trim_range)]
+    /// assert_eq!("  abc  ".trim_end_range(), 0..5);
+    /// assert_eq!(" \t\n".trim_end_range(), 0..0);
     /// ```
     #[inline]
-    #[must_use = "this returns the trimmed string as a new slice, \
-                  without modifying the original"]
-    #[stable(feature = "trim_direction", since = "1.30.0")]
-    #[cfg_attr(not(test), rustc_diagnostic_item = "str_trim_end")]
-    pub fn trim_end(&self) -> &str {
-        self.trim_end_matches(|c: char| c.is_whitespace())
+    #[must_use]
+    #[unstable(feature = "str_trim_range", issue = "none")]
+    pub fn trim_end_range(&self) -> Range<usize> {
+        0..self.trim_end().len()
     }
 
     /// Returns a string slice with leading whitespace removed.
@@ -2158,6 +3567,114 @@ pub fn strip_suffix<'a, P>(&'a self, suffix: P) -> Option<&'a str>
         suffix.strip_suffix_of(self)
     }
 
+    /// Returns a string slice with both a prefix and a suffix removed.
+    ///
+    /// If the string starts with the pattern `prefix` and the rest of it ends
+    /// with the pattern `suffix`, returns the substring between them, wrapped
+    /// in `Some`. The suffix is only searched for after the prefix, so the two
+    /// never overlap.
+    ///
+    /// If either affix is missing, returns `None`.
+    ///
+    /// The [pattern] can be a `&str`, [`char`], a slice of [`char`]s, or a
+    /// function or closure that determines if a character matches.
+    ///
+    /// [`char`]: prim@char
+    /// [pattern]: self::pattern
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(str_strip_affixes)]
+    /// assert_eq!("<p>text</p>".strip_affixes("<p>", "</p>"), Some("text"));
+    /// assert_eq!("<p>text".strip_affixes("<p>", "</p>"), None);
+    /// assert_eq!("aba".strip_affixes("ab", "ba"), None);
+    /// ```
+    #[must_use = "this returns the remaining substring as a new slice, \
+                  without modifying the original"]
+    #[unstable(feature = "str_strip_affixes", issue = "none")]
+    pub fn strip_affixes<'a, P, S>(&'a self, prefix: P, suffix: S) -> Option<&'a str>
+    where
+        P: Pattern<'a>,
+        S: Pattern<'a>,
+        <S as Pattern<'a>>::Searcher: ReverseSearcher<'a>,
+    {
+        suffix.strip_suffix_of(prefix.strip_prefix_of(self)?)
+    }
+
+    /// Splits off the prefix matching a pattern, returning it along with the
+    /// rest of the string.
+    ///
+    /// If the string starts with the pattern `prefix`, returns the matched
+    /// prefix and the substring after it, wrapped in `Some`. This is like
+    /// [`strip_prefix`], but also tells which text was removed, which is
+    /// useful when the pattern can match more than one string.
+    ///
+    /// If the string does not start with `prefix`, returns `None`.
+    ///
+    /// The [pattern] can be a `&str`, [`char`], a slice of [`char`]s, or a
+    /// function or closure that determines if a character matches.
+    ///
+    /// [`char`]: prim@char
+    /// [pattern]: self::pattern
+    /// [`strip_prefix`]: str::strip_prefix
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(str_split_prefix)]
+    /// assert_eq!("Hello, world".split_prefix("Hello"), Some(("Hello", ", world")));
+    /// assert_eq!("Hello, world".split_prefix("world"), None);
+    /// assert_eq!("+1".split_prefix(['+', '-']), Some(("+", "1")));
+    /// ```
+    #[must_use = "this returns the split string as new slices, \
+                  without modifying the original"]
+    #[unstable(feature = "str_split_prefix", issue = "none")]
+    pub fn split_prefix<'a, P: Pattern<'a>>(&'a self, prefix: P) -> Option<(&'a str, &'a str)> {
+        let rest = prefix.strip_prefix_of(self)?;
+        let mid = self.len() - rest.len();
+        // SAFETY: `rest` is a suffix of `self` that starts on a char boundary.
+        Some(unsafe { (self.get_unchecked(..mid), rest) })
+    }
+
+    /// Splits off the suffix matching a pattern, returning the rest of the
+    /// string along with it.
+    ///
+    /// If the string ends with the pattern `suffix`, returns the substring
+    /// before the suffix and the matched suffix, wrapped in `Some`. This is
+    /// like [`strip_suffix`], but also tells which text was removed, which is
+    /// useful when the pattern can match more than one string.
+    ///
+    /// If the string does not end with `suffix`, returns `None`.
+    ///
+    /// The [pattern] can be a `&str`, [`char`], a slice of [`char`]s, or a
+    /// function or closure that determines if a character matches.
+    ///
+    /// [`char`]: prim@char
+    /// [pattern]: self::pattern
+    /// [`strip_suffix`]: str::strip_suffix
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(str_split_prefix)]
+    /// assert_eq!("Hello, world".split_suffix("world"), Some(("Hello, ", "world")));
+    /// assert_eq!("Hello, world".split_suffix("Hello"), None);
+    /// assert_eq!("1%".split_suffix(['%', '‰']), Some(("1", "%")));
+    /// ```
+    #[must_use = "this returns the split string as new slices, \
+                  without modifying the original"]
+    #[unstable(feature = "str_split_prefix", issue = "none")]
+    pub fn split_suffix<'a, P>(&'a self, suffix: P) -> Option<(&'a str, &'a str)>
+    where
+        P: Pattern<'a>,
+        <P as Pattern<'a>>::Searcher: ReverseSearcher<'a>,
+    {
+        let rest = suffix.strip_suffix_of(self)?;
+        // SAFETY: `rest` is a prefix of `self` that ends on a char boundary.
+        Some(unsafe { (rest, self.get_unchecked(rest.len()..)) })
+    }
+
     /// Returns a string slice with all suffixes that match a pattern
     /// repeatedly removed.
     ///
@@ -2207,6 +3724,89 @@ pub fn trim_end_matches<'a, P>(&'a self, pat: P) -> &'a str
         unsafe { self.get_unchecked(0..j) }
     }
 
+    /// Returns a string slice with at most `n` prefixes that match a pattern
+    /// removed.
+    ///
+    /// This is like [`trim_start_matches`], but stops after removing `n`
+    /// matches. Unlike [`strip_prefix`], it returns the string unchanged if
+    /// it does not start with the pattern.
+    ///
+    /// The [pattern] can be a `&str`, [`char`], a slice of [`char`]s, or a
+    /// function or closure that determines if a character matches.
+    ///
+    /// [`char`]: prim@char
+    /// [pattern]: self::pattern
+    /// [`trim_start_matches`]: str::trim_start_matches
+    /// [`strip_prefix`]: str::strip_prefix
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(str_trim_matches_n)]
+    /// assert_eq!("...abc".trim_start_matches_n('.', 2), ".abc");
+    /// assert_eq!("...abc".trim_start_matches_n('.', 5), "abc");
+    /// assert_eq!("...abc".trim_start_matches_n('.', 0), "...abc");
+    /// assert_eq!("foofoobar".trim_start_matches_n("foo", 1), "foobar");
+    /// ```
+    #[must_use = "this returns the trimmed string as a new slice, \
+                  without modifying the original"]
+    #[unstable(feature = "str_trim_matches_n", issue = "none")]
+    pub fn trim_start_matches_n<'a, P: Pattern<'a>>(&'a self, pat: P, n: usize) -> &'a str {
+        let mut i = 0;
+        let mut matcher = pat.into_searcher(self);
+        for _ in 0..n {
+            match matcher.next() {
+                SearchStep::Match(_, b) => i = b,
+                SearchStep::Reject(..) | SearchStep::Done => break,
+            }
+        }
+        // SAFETY: `Searcher` is known to return valid indices.
+        unsafe { self.get_unchecked(i..self.len()) }
+    }
+
+    /// Returns a string slice with at most `n` suffixes that match a pattern
+    /// removed.
+    ///
+    /// This is like [`trim_end_matches`], but stops after removing `n`
+    /// matches. Unlike [`strip_suffix`], it returns the string unchanged if
+    /// it does not end with the pattern.
+    ///
+    /// The [pattern] can be a `&str`, [`char`], a slice of [`char`]s, or a
+    /// function or closure that determines if a character matches.
+    ///
+    /// [`char`]: prim@char
+    /// [pattern]: self::pattern
+    /// [`trim_end_matches`]: str::trim_end_matches
+    /// [`strip_suffix`]: str::strip_suffix
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(str_trim_matches_n)]
+    /// assert_eq!("abc...".trim_end_matches_n('.', 2), "abc.");
+    /// assert_eq!("abc...".trim_end_matches_n('.', 5), "abc");
+    /// assert_eq!("abc...".trim_end_matches_n('.', 0), "abc...");
+    /// assert_eq!("barfoofoo".trim_end_matches_n("foo", 1), "barfoo");
+    /// ```
+    #[must_use = "this returns the trimmed string as a new slice, \
+                  without modifying the original"]
+    #[unstable(feature = "str_trim_matches_n", issue = "none")]
+    pub fn trim_end_matches_n<'a, P>(&'a self, pat: P, n: usize) -> &'a str
+    where
+        P: Pattern<'a, Searcher: ReverseSearcher<'a>>,
+    {
+        let mut j = self.len();
+        let mut matcher = pat.into_searcher(self);
+        for _ in 0..n {
+            match matcher.next_back() {
+                SearchStep::Match(a, _) => j = a,
+                SearchStep::Reject(..) | SearchStep::Done => break,
+            }
+        }
+        // SAFETY: `Searcher` is known to return valid indices.
+        unsafe { self.get_unchecked(0..j) }
+    }
+
     /// Returns a string slice with all prefixes that match a pattern
     /// repeatedly removed.
     ///
@@ -2379,6 +3979,49 @@ pub fn eq_ignore_ascii_case(&self, other: &str) -> bool {
         self.as_bytes().eq_ignore_ascii_case(other.as_bytes())
     }
 
+    /// Checks that two strings are a Unicode case-insensitive match.
+    ///
+    /// Both strings are compared as if each had been case folded, but without
+    /// allocating. Folding may map one character to several, so strings of
+    /// different lengths can still match: `'ß'` folds to `"ss"`.
+    ///
+    /// The folding is the full, language-independent one of the Unicode
+    /// Standard. It does not apply Turkic rules, so the dotless `'ı'` only
+    /// matches itself.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(str_eq_ignore_case)]
+    /// assert!("Ferris".eq_ignore_case("FERRIS"));
+    /// assert!("Ferrös".eq_ignore_case("FERRÖS"));
+    /// assert!("STRASSE".eq_ignore_case("straße"));
+    /// assert!("ΣΊΣΥΦΟΣ".eq_ignore_case("σίσυφος"));
+    /// assert!(!"Ferris".eq_ignore_case("Ferrös"));
+    /// ```
+    #[unstable(feature = "str_eq_ignore_case", issue = "none")]
+    #[must_use]
+    pub fn eq_ignore_case(&self, other: &str) -> bool {
+        // Non-ASCII characters such as the Kelvin sign can fold into ASCII,
+        // so the cheap comparison is only correct if both sides are ASCII.
+        if self.is_ascii() && other.is_ascii() {
+            return self.eq_ignore_ascii_case(other);
+        }
+
+        // Lowercasing, then uppercasing and lowercasing again, reaches the
+        // same representative for every character of a case folding class,
+        // e.g. 'ẞ' → 'ß' → "SS" → "ss". The one exception is 'ı', whose
+        // uppercase 'I' belongs to a different class, so it is kept as is.
+        fn case_fold(s: &str) -> impl Iterator<Item = char> + '_ {
+            s.chars()
+                .flat_map(|c| c.to_lowercase())
+                .flat_map(|c| c.to_uppercase().map(move |u| if c == 'ı' { c } else { u }))
+                .flat_map(|c| c.to_lowercase())
+        }
+
+        case_fold(self).eq(case_fold(other))
+    }
+
     /// Converts this string to its ASCII upper case equivalent in-place.
     ///
     /// ASCII letters 'a' to 'z' are mapped to 'A' to 'Z',
@@ -2433,6 +4076,68 @@ pub fn make_ascii_lowercase(&mut self) {
         me.make_ascii_lowercase()
     }
 
+    /// Writes this string's ASCII upper case equivalent into `buf`, without
+    /// allocating.
+    ///
+    /// ASCII letters 'a' to 'z' are mapped to 'A' to 'Z', and all other bytes,
+    /// including those of non-ASCII characters, are copied unchanged. The
+    /// written bytes are therefore always valid UTF-8.
+    ///
+    /// Returns the number of bytes written, which is always `self.len()`. If
+    /// `buf` is shorter than that, nothing is written and the required
+    /// length is returned as the error.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(str_to_ascii_case_into)]
+    ///
+    /// let mut buf = [0; 16];
+    /// let len = "Grüße, Jürgen".to_ascii_uppercase_into(&mut buf).unwrap();
+    /// assert_eq!(std::str::from_utf8(&buf[..len]), Ok("GRüßE, JüRGEN"));
+    ///
+    /// assert_eq!("Grüße".to_ascii_uppercase_into(&mut [0; 4]), Err(7));
+    /// ```
+    #[unstable(feature = "str_to_ascii_case_into", issue = "none")]
+    #[inline]
+    pub fn to_ascii_uppercase_into(&self, buf: &mut [u8]) -> Result<usize, usize> {
+        let out = buf.get_mut(..self.len()).ok_or(self.len())?;
+        out.copy_from_slice(self.as_bytes());
+        out.make_ascii_uppercase();
+        Ok(out.len())
+    }
+
+    /// Writes this string's ASCII lower case equivalent into `buf`, without
+    /// allocating.
+    ///
+    /// ASCII letters 'A' to 'Z' are mapped to 'a' to 'z', and all other bytes,
+    /// including those of non-ASCII characters, are copied unchanged. The
+    /// written bytes are therefore always valid UTF-8.
+    ///
+    /// Returns the number of bytes written, which is always `self.len()`. If
+    /// `buf` is shorter than that, nothing is written and the required
+    /// length is returned as the error.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(str_to_ascii_case_into)]
+    ///
+    /// let mut buf = [0; 16];
+    /// let len = "GRÜßE, JÜRGEN".to_ascii_lowercase_into(&mut buf).unwrap();
+    /// assert_eq!(std::str::from_utf8(&buf[..len]), Ok("grÜße, jÜrgen"));
+    ///
+    /// assert_eq!("GRÜßE".to_ascii_lowercase_into(&mut [0; 4]), Err(7));
+    /// ```
+    #[unstable(feature = "str_to_ascii_case_into", issue = "none")]
+    #[inline]
+    pub fn to_ascii_lowercase_into(&self, buf: &mut [u8]) -> Result<usize, usize> {
+        let out = buf.get_mut(..self.len()).ok_or(self.len())?;
+        out.copy_from_slice(self.as_bytes());
+        out.make_ascii_lowercase();
+        Ok(out.len())
+    }
+
     /// Return an iterator that escapes each char in `self` with [`char::escape_debug`].
     ///
     /// Note: only extended grapheme codepoints that begin the string will be
@@ -2557,6 +4262,151 @@ pub fn escape_default(&self) -> EscapeDefault<'_> {
     pub fn escape_unicode(&self) -> EscapeUnicode<'_> {
         EscapeUnicode { inner: self.chars().flat_map(CharEscapeUnicode) }
     }
+
+    /// Return an iterator that escapes each byte of `self` with
+    /// [`ascii::escape_default`], as [`slice::escape_ascii`] does.
+    ///
+    /// Printable ASCII characters are yielded unchanged, apart from quotes
+    /// and backslashes. Every byte of a non-ASCII character is escaped
+    /// separately as `\xNN`, so the output is always printable ASCII
+    /// that fits on a single line.
+    ///
+    /// [`ascii::escape_default`]: crate::ascii::escape_default
+    ///
+    /// # Examples
+    ///
+    /// As an iterator:
+    ///
+    /// ```
+    /// #![feature(str_escape_ascii)]
+    ///
+    /// for c in "€\n!".escape_ascii() {
+    ///     print!("{c}");
+    /// }
+    /// println!();
+    /// ```
+    ///
+    /// Using `println!` directly:
+    ///
+    /// ```
+    /// #![feature(str_escape_ascii)]
+    ///
+    /// println!("{}", "€\n!".escape_ascii());
+    /// ```
+    ///
+    ///
+    /// Both are equivalent to:
+    ///
+    /// ```
+    /// println!("\\xe2\\x82\\xac\\n!");
+    /// ```
+    ///
+    /// Using `to_string`:
+    ///
+    /// ```
+    /// #![feature(str_escape_ascii)]
+    ///
+    /// assert_eq!("€\n!".escape_ascii().to_string(), "\\xe2\\x82\\xac\\n!");
+    /// ```
+    #[must_use = "this returns the escaped string as an iterator, \
+                  without modifying the original"]
+    #[unstable(feature = "str_escape_ascii", issue = "none")]
+    pub fn escape_ascii(&self) -> EscapeAscii<'_> {
+        EscapeAscii { inner: self.as_bytes().escape_ascii() }
+    }
+
+    /// Return an iterator that escapes the control characters in `self`,
+    /// leaving every other char as it is.
+    ///
+    /// Control characters are those of the Unicode general category `Cc`,
+    /// i.e. the C0 and C1 control codes and DEL; see [`char::is_control`].
+    /// They are escaped as by [`char::escape_debug`], so a line feed becomes
+    /// `\n` and the bell character becomes `\u{7}`. Unlike [`str::escape_debug`],
+    /// printable text, including quotes, backslashes and any non-ASCII
+    /// character, is never escaped.
+    ///
+    /// This is useful for writing untrusted text to a terminal without
+    /// letting it emit control sequences.
+    ///
+    /// # Examples
+    ///
+    /// As an iterator:
+    ///
+    /// ```
+    /// #![feature(str_escape_control)]
+    /// for c in "❤\u{1b}[2J".escape_control() {
+    ///     print!("{c}");
+    /// }
+    /// println!();
+    /// ```
+    ///
+    /// Using `println!` directly:
+    ///
+    /// ```
+    /// #![feature(str_escape_control)]
+    /// println!("{}", "❤\u{1b}[2J".escape_control());
+    /// ```
+    ///
+    ///
+    /// Both are equivalent to:
+    ///
+    /// ```
+    /// println!("❤\\u{{1b}}[2J");
+    /// ```
+    ///
+    /// Using `to_string`:
+    ///
+    /// ```
+    /// #![feature(str_escape_control)]
+    /// assert_eq!("❤\u{1b}[2J \"\\\n".escape_control().to_string(), "❤\\u{1b}[2J \"\\\\n");
+    /// ```
+    #[must_use = "this returns the escaped string as an iterator, \
+                  without modifying the original"]
+    #[unstable(feature = "str_escape_control", issue = "none")]
+    pub fn escape_control(&self) -> EscapeControl<'_> {
+        EscapeControl { inner: self.chars().flat_map(CharEscapeControl) }
+    }
+
+    /// Writes `n` copies of this string slice to the start of `buf`,
+    /// returning the number of bytes written.
+    ///
+    /// This is the non-allocating counterpart of `str::repeat`. The written
+    /// bytes `buf[..len]` are valid UTF-8, since they are whole copies of
+    /// `self`.
+    ///
+    /// # Errors
+    ///
+    /// If `buf` is too small to hold all `n` copies, nothing is written and
+    /// the required length is returned in `Err`. If that length would overflow
+    /// a `usize`, `Err(usize::MAX)` is returned.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// #![feature(str_repeat_into)]
+    /// let mut buf = [0; 8];
+    ///
+    /// assert_eq!("ab".repeat_into(3, &mut buf), Ok(6));
+    /// assert_eq!(&buf[..6], b"ababab");
+    ///
+    /// assert_eq!("ab".repeat_into(5, &mut buf), Err(10));
+    /// ```
+    #[unstable(feature = "str_repeat_into", issue = "none")]
+    pub fn repeat_into(&self, n: usize, buf: &mut [u8]) -> Result<usize, usize> {
+        let len = match self.len().checked_mul(n) {
+            Some(0) => return Ok(0),
+            Some(len) => len,
+            None => return Err(usize::MAX),
+        };
+        let buf = match buf.get_mut(..len) {
+            Some(buf) => buf,
+            None => return Err(len),
+        };
+        for copy in buf.chunks_exact_mut(self.len()) {
+            copy.copy_from_slice(self.as_bytes());
+        }
+        Ok(len)
+    }
 }
 
 #[stable(feature = "rust1", since = "1.0.0")]
@@ -2605,6 +4455,11 @@ struct CharEscapeDebugContinue impl Fn = |c: char| -> char::EscapeDebug {
         })
     };
 
+    #[derive(Clone)]
+    struct CharEscapeControl impl Fn = |c: char| -> char::EscapeDebug {
+        c.escape_control()
+    };
+
     #[derive(Clone)]
     struct CharEscapeUnicode impl Fn = |c: char| -> char::EscapeUnicode {
         c.escape_unicode()
@@ -2619,6 +4474,11 @@ struct IsWhitespace impl Fn = |c: char| -> bool {
         c.is_whitespace()
     };
 
+    #[derive(Clone)]
+    struct TrimWhitespace impl<'a> Fn = |s: &'a str| -> &'a str {
+        s.trim()
+    };
+
     #[derive(Clone)]
     struct IsAsciiWhitespace impl Fn = |byte: &u8| -> bool {
         byte.is_ascii_whitespace()
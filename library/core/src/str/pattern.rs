@@ -433,6 +433,10 @@ fn next_match(&mut self) -> Option<(usize, usize)> {
                 // find something. When we find something the `finger` will be set
                 // to a UTF8 boundary.
                 self.finger += index + 1;
+                if self.utf8_size == 1 {
+                    // An ASCII byte is a char of its own, so every hit is a match.
+                    return Some((self.finger - 1, self.finger));
+                }
                 if self.finger >= self.utf8_size {
                     let found_char = self.finger - self.utf8_size;
                     if let Some(slice) = self.haystack.as_bytes().get(found_char..self.finger) {
@@ -486,6 +490,11 @@ fn next_match_back(&mut self) -> Option<(usize, usize)> {
                 // we searched a slice that was offset by self.finger,
                 // add self.finger to recoup the original index
                 let index = self.finger + index;
+                if self.utf8_size == 1 {
+                    // An ASCII byte is a char of its own, so every hit is a match.
+                    self.finger_back = index;
+                    return Some((index, index + 1));
+                }
                 // memrchr will return the index of the byte we wish to
                 // find. In case of an ASCII character, this is indeed
                 // were we wish our new finger to be ("after" the found
@@ -528,6 +537,14 @@ fn next_match_back(&mut self) -> Option<(usize, usize)> {
 
 impl<'a> DoubleEndedSearcher<'a> for CharSearcher<'a> {}
 
+impl CharSearcher<'_> {
+    /// Returns the needle as a byte, if it is ASCII.
+    #[inline]
+    pub(super) fn ascii_needle(&self) -> Option<u8> {
+        if self.utf8_size == 1 { Some(self.utf8_encoded[0]) } else { None }
+    }
+}
+
 /// Searches for chars that are equal to a given [`char`].
 ///
 /// # Examples
@@ -909,6 +926,163 @@ impl<'a, F> Pattern<'a> for F
     pattern_methods!(CharPredicateSearcher<'a, F>, MultiCharEqPattern, CharPredicateSearcher);
 }
 
+/////////////////////////////////////////////////////////////////////////////
+// Impl for LineEnding
+/////////////////////////////////////////////////////////////////////////////
+
+/// A pattern matching a line ending, either `"\n"` or `"\r\n"`.
+///
+/// A `"\r\n"` is always matched as a single unit, never as a `"\n"` on
+/// its own. A lone `"\r"` is not a line ending. This is the same notion of
+/// line ending as used by [`str::lines`].
+///
+/// # Examples
+///
+/// ```
+/// #![feature(pattern)]
+/// use std::str::pattern::LineEnding;
+///
+/// let v: Vec<_> = "a\r\nb\n".match_indices(LineEnding).collect();
+/// assert_eq!(v, [(1, "\r\n"), (4, "\n")]);
+///
+/// let v: Vec<&str> = "a\r\nb\nc\rd".split(LineEnding).collect();
+/// assert_eq!(v, ["a", "b", "c\rd"]);
+///
+/// let v: Vec<&str> = "a\r\nb\n".split_inclusive(LineEnding).collect();
+/// assert_eq!(v, ["a\r\n", "b\n"]);
+/// ```
+#[derive(Copy, Clone, Debug)]
+pub struct LineEnding;
+
+/// Associated type for `<LineEnding as Pattern<'a>>::Searcher`.
+#[derive(Clone, Debug)]
+pub struct LineEndingSearcher<'a> {
+    haystack: &'a str,
+    /// The current byte index of the forward search.
+    finger: usize,
+    /// The current byte index of the reverse search.
+    finger_back: usize,
+}
+
+impl<'a> LineEndingSearcher<'a> {
+    /// Returns the start of the line ending whose `'\n'` is at `newline`:
+    /// the index of a directly preceding `'\r'` that has not been searched
+    /// yet, or `newline` itself.
+    #[inline]
+    fn ending_start(&self, newline: usize) -> usize {
+        if newline > self.finger && self.haystack.as_bytes()[newline - 1] == b'\r' {
+            newline - 1
+        } else {
+            newline
+        }
+    }
+}
+
+// All indices produced below point at or just after an ASCII byte, or are
+// `finger`/`finger_back`, which are only ever set to such indices, so they
+// always lie on char boundaries.
+unsafe impl<'a> Searcher<'a> for LineEndingSearcher<'a> {
+    #[inline]
+    fn haystack(&self) -> &'a str {
+        self.haystack
+    }
+    #[inline]
+    fn next(&mut self) -> SearchStep {
+        let bytes = &self.haystack.as_bytes()[self.finger..self.finger_back];
+        match memchr::memchr(b'\n', bytes) {
+            Some(index) => {
+                let newline = self.finger + index;
+                let start = self.ending_start(newline);
+                if start > self.finger {
+                    let old_finger = self.finger;
+                    self.finger = start;
+                    SearchStep::Reject(old_finger, start)
+                } else {
+                    self.finger = newline + 1;
+                    SearchStep::Match(start, newline + 1)
+                }
+            }
+            None if self.finger < self.finger_back => {
+                let old_finger = self.finger;
+                self.finger = self.finger_back;
+                SearchStep::Reject(old_finger, self.finger_back)
+            }
+            None => SearchStep::Done,
+        }
+    }
+    #[inline]
+    fn next_match(&mut self) -> Option<(usize, usize)> {
+        let bytes = &self.haystack.as_bytes()[self.finger..self.finger_back];
+        match memchr::memchr(b'\n', bytes) {
+            Some(index) => {
+                let newline = self.finger + index;
+                let start = self.ending_start(newline);
+                self.finger = newline + 1;
+                Some((start, newline + 1))
+            }
+            None => {
+                self.finger = self.finger_back;
+                None
+            }
+        }
+    }
+}
+
+unsafe impl<'a> ReverseSearcher<'a> for LineEndingSearcher<'a> {
+    #[inline]
+    fn next_back(&mut self) -> SearchStep {
+        let bytes = &self.haystack.as_bytes()[self.finger..self.finger_back];
+        match memchr::memrchr(b'\n', bytes) {
+            Some(index) => {
+                let newline = self.finger + index;
+                if newline + 1 < self.finger_back {
+                    let old_finger = self.finger_back;
+                    self.finger_back = newline + 1;
+                    SearchStep::Reject(newline + 1, old_finger)
+                } else {
+                    let start = self.ending_start(newline);
+                    self.finger_back = start;
+                    SearchStep::Match(start, newline + 1)
+                }
+            }
+            None if self.finger < self.finger_back => {
+                let old_finger = self.finger_back;
+                self.finger_back = self.finger;
+                SearchStep::Reject(self.finger, old_finger)
+            }
+            None => SearchStep::Done,
+        }
+    }
+    #[inline]
+    fn next_match_back(&mut self) -> Option<(usize, usize)> {
+        let bytes = &self.haystack.as_bytes()[self.finger..self.finger_back];
+        match memchr::memrchr(b'\n', bytes) {
+            Some(index) => {
+                let newline = self.finger + index;
+                let start = self.ending_start(newline);
+                self.finger_back = start;
+                Some((start, newline + 1))
+            }
+            None => {
+                self.finger_back = self.finger;
+                None
+            }
+        }
+    }
+}
+
+impl<'a> DoubleEndedSearcher<'a> for LineEndingSearcher<'a> {}
+
+/// Searches for line endings, `"\n"` or `"\r\n"`.
+impl<'a> Pattern<'a> for LineEnding {
+    type Searcher = LineEndingSearcher<'a>;
+
+    #[inline]
+    fn into_searcher(self, haystack: &'a str) -> LineEndingSearcher<'a> {
+        LineEndingSearcher { haystack, finger: 0, finger_back: haystack.len() }
+    }
+}
+
 /////////////////////////////////////////////////////////////////////////////
 // Impl for &&str
 /////////////////////////////////////////////////////////////////////////////
@@ -1030,6 +1204,31 @@ fn new(haystack: &'a str, needle: &'b str) -> StrSearcher<'a, 'b> {
             }
         }
     }
+
+    /// If the needle is empty, returns bounds on the number of matches left,
+    /// assuming the searcher is only ever driven from one end.
+    ///
+    /// An empty needle matches at every char boundary. Counting those would
+    /// take a scan, so the bounds come from the length of the unsearched part
+    /// of the haystack instead: each char in it takes one to four bytes.
+    #[inline]
+    pub(super) fn empty_needle_match_bounds(&self) -> Option<(usize, Option<usize>)> {
+        match self.searcher {
+            StrSearcherImpl::Empty(ref searcher) => {
+                if searcher.is_finished || searcher.position > searcher.end {
+                    return Some((0, Some(0)));
+                }
+                // One boundary before each char and one at the end, minus the
+                // ones at either end that have already been matched.
+                let len = searcher.end - searcher.position;
+                let matched = !searcher.is_match_fw as usize + !searcher.is_match_bw as usize;
+                let lower = ((len + 3) / 4 + 1).saturating_sub(matched);
+                let upper = (len + 1).saturating_sub(matched);
+                Some((lower, Some(upper)))
+            }
+            StrSearcherImpl::TwoWay(..) => None,
+        }
+    }
 }
 
 unsafe impl<'a, 'b> Searcher<'a> for StrSearcher<'a, 'b> {
@@ -47,6 +47,38 @@ fn test_to_ascii_lowercase() {
     }
 }
 
+#[test]
+fn test_to_ascii_case_into() {
+    let mut buf = [0xff; 64];
+    let s = "url()URL()uRl()Ürl";
+    assert_eq!(s.to_ascii_lowercase_into(&mut buf), Ok(s.len()));
+    assert_eq!(core::str::from_utf8(&buf[..s.len()]), Ok("url()url()url()Ürl"));
+    // Bytes past the output are left alone.
+    assert!(buf[s.len()..].iter().all(|&b| b == 0xff));
+    assert_eq!(s.to_ascii_uppercase_into(&mut buf), Ok(s.len()));
+    assert_eq!(core::str::from_utf8(&buf[..s.len()]), Ok("URL()URL()URL()ÜRL"));
+
+    // Non-ASCII bytes are copied verbatim, even for U+212A KELVIN SIGN, which
+    // lowercases to an ASCII `k` under full Unicode case mapping.
+    let s = "HİKß ประเทศไทย";
+    let len = s.to_ascii_lowercase_into(&mut buf).unwrap();
+    assert_eq!(core::str::from_utf8(&buf[..len]), Ok("hİKß ประเทศไทย"));
+    assert_eq!(buf[1..len], s.as_bytes()[1..]);
+    let len = "hıkß".to_ascii_uppercase_into(&mut buf).unwrap();
+    assert_eq!(core::str::from_utf8(&buf[..len]), Ok("HıKß"));
+
+    // A buffer of exactly the right length is enough.
+    assert_eq!("aB".to_ascii_uppercase_into(&mut buf[..2]), Ok(2));
+    assert_eq!(buf[..2], *b"AB");
+    assert_eq!("".to_ascii_lowercase_into(&mut []), Ok(0));
+
+    // A short buffer reports the required length and is left untouched.
+    let mut short = [0; 3];
+    assert_eq!("Ürl".to_ascii_lowercase_into(&mut short), Err(4));
+    assert_eq!("Ürl".to_ascii_uppercase_into(&mut short), Err(4));
+    assert_eq!(short, [0; 3]);
+}
+
 #[test]
 fn test_make_ascii_lower_case() {
     macro_rules! test {
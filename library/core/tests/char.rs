@@ -308,6 +308,19 @@ fn check(s: &[u16], expected: &[Result<char, u16>]) {
     check(&[0xD800, 0], &[Err(0xD800), Ok('\0')]);
 }
 
+#[test]
+fn test_from_utf16_lossy_chars() {
+    let s = "a𝄞b";
+    let v: String = char::from_utf16_lossy_chars(s.encode_utf16()).collect();
+    assert_eq!(v, s);
+
+    let mut units: Vec<u16> = s.encode_utf16().collect();
+    units.insert(1, 0xDC00);
+    units.push(0xD800);
+    let v: String = char::from_utf16_lossy_chars(units).collect();
+    assert_eq!(v, "a\u{FFFD}𝄞b\u{FFFD}");
+}
+
 #[test]
 fn test_decode_utf16_size_hint() {
     fn check(s: &[u16]) {
@@ -46,6 +46,10 @@
 #![feature(sort_internals)]
 #![feature(slice_take)]
 #![feature(slice_from_ptr_range)]
+#![feature(slice_split_once)]
+#![feature(slice_split_off_prefix)]
+#![feature(slice_split_ranges)]
+#![feature(split_at_checked)]
 #![feature(split_as_slice)]
 #![feature(maybe_uninit_uninit_array)]
 #![feature(maybe_uninit_array_assume_init)]
@@ -54,6 +58,7 @@
 #![feature(numfmt)]
 #![feature(step_trait)]
 #![feature(str_internals)]
+#![feature(str_to_ascii_case_into)]
 #![feature(std_internals)]
 #![feature(test)]
 #![feature(trusted_len)]
@@ -101,6 +106,7 @@
 #![feature(slice_flatten)]
 #![feature(provide_any)]
 #![feature(utf8_chunks)]
+#![feature(from_utf16_lossy_chars)]
 #![deny(unsafe_op_in_unsafe_fn)]
 
 extern crate test;
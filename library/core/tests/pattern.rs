@@ -501,3 +501,30 @@ fn double_ended_regression_test() {
         [InRange(10, 13), Rejects(13, 14), InRange(37, 40), Rejects(34, 37), Done]
     );
 }
+
+#[test]
+fn ascii_search_agrees_with_char_decoding() {
+    // `next_match` and `next_match_back` take a shortcut for ASCII needles.
+    // Check that it agrees with an equivalent predicate, which has to decode
+    // every char, under every mix of the four search steps.
+    for haystack in [STRESS, "a,b,,c,", ",", "", ",çà,,é"] {
+        for needle in ['a', 'b', ',', 'x'] {
+            for mut ops in 0u32..1 << 12 {
+                let mut fast = needle.into_searcher(haystack);
+                let mut slow = (|c: char| c == needle).into_searcher(haystack);
+                for _ in 0..6 {
+                    let (a, b) = match ops % 4 {
+                        0 => (Step::from(fast.next()), Step::from(slow.next())),
+                        1 => (Step::from(fast.next_back()), Step::from(slow.next_back())),
+                        2 => (Step::from(fast.next_match()), Step::from(slow.next_match())),
+                        _ => {
+                            (Step::from(fast.next_match_back()), Step::from(slow.next_match_back()))
+                        }
+                    };
+                    assert_eq!(a, b, "searching {haystack:?} for {needle:?}");
+                    ops /= 4;
+                }
+            }
+        }
+    }
+}
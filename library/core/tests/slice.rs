@@ -2417,6 +2417,176 @@ fn split_as_slice() {
     assert_eq!(split.as_slice(), &[]);
 }
 
+#[test]
+fn slice_split_ranges() {
+    let v = [1, 0, 2, 0, 3];
+    let ranges: Vec<_> = v.split_ranges(|&x| x == 0).collect();
+    assert_eq!(ranges, [0..1, 2..3, 4..5]);
+    let ranges: Vec<_> = v.split_ranges(|&x| x == 0).rev().collect();
+    assert_eq!(ranges, [4..5, 2..3, 0..1]);
+
+    // Leading, trailing and consecutive delimiters give empty ranges.
+    let v = [0, 1, 0, 0, 2, 0];
+    let ranges: Vec<_> = v.split_ranges(|&x| x == 0).collect();
+    assert_eq!(ranges, [0..0, 1..2, 3..3, 4..5, 6..6]);
+    let ranges: Vec<_> = v.split_ranges(|&x| x == 0).rev().collect();
+    assert_eq!(ranges, [6..6, 4..5, 3..3, 1..2, 0..0]);
+
+    let mut iter = v.split_ranges(|&x| x == 0);
+    assert_eq!(iter.next(), Some(0..0));
+    assert_eq!(iter.next_back(), Some(6..6));
+    assert_eq!(iter.next(), Some(1..2));
+    assert_eq!(iter.next_back(), Some(4..5));
+    assert_eq!(iter.next(), Some(3..3));
+    assert_eq!(iter.next_back(), None);
+    assert_eq!(iter.next(), None);
+
+    let v: [i32; 0] = [];
+    let ranges: Vec<_> = v.split_ranges(|&x| x == 0).collect();
+    assert_eq!(ranges, [0..0]);
+
+    let v = [(), (), ()];
+    let ranges: Vec<_> = v.split_ranges(|_| true).collect();
+    assert_eq!(ranges, [0..0, 1..1, 2..2, 3..3]);
+
+    let v = [1, 2, 0, 3];
+    let pieces: Vec<&[i32]> = v.split(|&x| x == 0).collect();
+    let ranges: Vec<&[i32]> = v.split_ranges(|&x| x == 0).map(|r| &v[r]).collect();
+    assert_eq!(pieces, ranges);
+}
+
+#[test]
+fn slice_split_once() {
+    let v = &[1, 2, 0, 3][..];
+    assert_eq!(v.split_once(|&x| x == 0), Some((&[1, 2][..], &[3][..])));
+    assert_eq!(v.split_once(|&x| x == 1), Some((&[][..], &[2, 0, 3][..])));
+    assert_eq!(v.split_once(|&x| x == 3), Some((&[1, 2, 0][..], &[][..])));
+    assert_eq!(v.split_once(|&x| x == 4), None);
+    assert_eq!(v.split_once(|_| true), Some((&[][..], &[2, 0, 3][..])));
+
+    let v = &[1, 0, 2, 0, 3][..];
+    assert_eq!(v.split_once(|&x| x == 0), Some((&[1][..], &[2, 0, 3][..])));
+    assert_eq!(v.rsplit_once(|&x| x == 0), Some((&[1, 0, 2][..], &[3][..])));
+    assert_eq!(v.rsplit_once(|_| true), Some((&[1, 0, 2, 0][..], &[][..])));
+
+    let v: &[i32] = &[];
+    assert_eq!(v.split_once(|_| true), None);
+    assert_eq!(v.rsplit_once(|_| true), None);
+}
+
+#[test]
+fn slice_rsplit_once() {
+    let v = &[1, 0, 2, 0, 3][..];
+    assert_eq!(v.rsplit_once(|&x| x == 0), Some((&[1, 0, 2][..], &[3][..])));
+    assert_eq!(v.rsplit_once(|&x| x == 4), None);
+
+    // Delimiter at the start or the end.
+    assert_eq!(v.rsplit_once(|&x| x == 1), Some((&[][..], &[0, 2, 0, 3][..])));
+    assert_eq!(v.rsplit_once(|&x| x == 3), Some((&[1, 0, 2, 0][..], &[][..])));
+
+    let v = &[0][..];
+    assert_eq!(v.rsplit_once(|&x| x == 0), Some((&[][..], &[][..])));
+
+    let v = &mut [0, 1, 0][..];
+    let (left, right) = v.rsplit_once_mut(|&x| x == 0).unwrap();
+    assert_eq!(left, [0, 1]);
+    assert!(right.is_empty());
+    left[0] = 2;
+    let (left, right) = v.rsplit_once_mut(|&x| x == 2).unwrap();
+    assert!(left.is_empty());
+    assert_eq!(right, [1, 0]);
+    right[0] = 3;
+    assert_eq!(v, [2, 3, 0]);
+    assert!(v.rsplit_once_mut(|&x| x == 4).is_none());
+}
+
+#[test]
+fn slice_split_off_prefix() {
+    let v = &[1, 1, 0, 2][..];
+    assert_eq!(v.split_off_prefix(|&x| x != 0), (&[1, 1][..], &[0, 2][..]));
+    assert_eq!(v.split_off_suffix(|&x| x != 0), (&[1, 1, 0][..], &[2][..]));
+
+    // All elements match.
+    assert_eq!(v.split_off_prefix(|_| true), (v, &[][..]));
+    assert_eq!(v.split_off_suffix(|_| true), (&[][..], v));
+
+    // No element matches.
+    assert_eq!(v.split_off_prefix(|_| false), (&[][..], v));
+    assert_eq!(v.split_off_suffix(|_| false), (v, &[][..]));
+
+    let v: &[i32] = &[];
+    assert_eq!(v.split_off_prefix(|_| true), (&[][..], &[][..]));
+    assert_eq!(v.split_off_suffix(|_| false), (&[][..], &[][..]));
+
+    let v = &mut [1, 1, 0, 2, 2][..];
+    let (prefix, rest) = v.split_off_prefix_mut(|&x| x == 1);
+    assert_eq!(prefix, [1, 1]);
+    prefix[0] = 3;
+    rest[0] = 4;
+    let (rest, suffix) = v.split_off_suffix_mut(|&x| x == 2);
+    assert_eq!(rest, [3, 1, 4]);
+    suffix[1] = 5;
+    assert_eq!(v, [3, 1, 4, 2, 5]);
+    let (prefix, rest) = v.split_off_prefix_mut(|_| true);
+    assert_eq!(prefix.len(), 5);
+    assert!(rest.is_empty());
+    let (rest, suffix) = v.split_off_suffix_mut(|_| false);
+    assert_eq!(rest.len(), 5);
+    assert!(suffix.is_empty());
+}
+
+#[test]
+fn slice_split_once_mut() {
+    let v = &mut [1, 0, 2, 0, 3][..];
+    let (left, right) = v.split_once_mut(|&x| x == 0).unwrap();
+    assert_eq!(left, [1]);
+    assert_eq!(right, [2, 0, 3]);
+    left[0] = 4;
+    right[0] = 5;
+    assert_eq!(v, [4, 0, 5, 0, 3]);
+
+    let (left, right) = v.rsplit_once_mut(|&x| x == 0).unwrap();
+    assert_eq!(left, [4, 0, 5]);
+    assert_eq!(right, [3]);
+    left[2] = 6;
+    right[0] = 7;
+    assert_eq!(v, [4, 0, 6, 0, 7]);
+
+    assert!(v.split_once_mut(|&x| x == 8).is_none());
+    assert!(v.rsplit_once_mut(|&x| x == 8).is_none());
+}
+
+#[test]
+fn slice_split_at_checked() {
+    let v = &[1, 2, 3, 4][..];
+    assert_eq!(v.split_at_checked(0), Some((&[][..], &[1, 2, 3, 4][..])));
+    assert_eq!(v.split_at_checked(1), Some((&[1][..], &[2, 3, 4][..])));
+    assert_eq!(v.split_at_checked(4), Some((&[1, 2, 3, 4][..], &[][..])));
+    assert_eq!(v.split_at_checked(5), None);
+    assert_eq!(v.split_at_checked(usize::MAX), None);
+
+    let v: &[i32] = &[];
+    assert_eq!(v.split_at_checked(0), Some((&[][..], &[][..])));
+    assert_eq!(v.split_at_checked(1), None);
+}
+
+#[test]
+fn slice_split_at_mut_checked() {
+    let v = &mut [1, 2, 3, 4][..];
+    let (left, right) = v.split_at_mut_checked(2).unwrap();
+    assert_eq!(left, [1, 2]);
+    assert_eq!(right, [3, 4]);
+    left[1] = 5;
+    right[0] = 6;
+    assert_eq!(v, [1, 5, 6, 4]);
+
+    let (left, right) = v.split_at_mut_checked(4).unwrap();
+    assert_eq!(left, [1, 5, 6, 4]);
+    assert!(right.is_empty());
+
+    assert!(v.split_at_mut_checked(5).is_none());
+}
+
 #[should_panic]
 #[test]
 fn slice_split_array_ref_out_of_bounds() {